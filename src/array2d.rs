@@ -2,6 +2,9 @@ use crate::traits::Mapped;
 use crate::Matrix;
 use either::{Either, Left, Right};
 
+// length when the identity, otherwise the permutation itself
+pub(crate) type Map = Either<usize, Vec<usize>>;
+
 #[derive(Debug, Clone)]
 pub struct Array2d<T> {
     rows: Either<usize, Vec<usize>>,
@@ -46,6 +49,27 @@ impl<T> Array2d<T> {
         }
     }
 
+    pub fn as_slice(&self) -> Option<&[T]> {
+        if self.rows.is_left() && self.columns.is_left() {
+            Some(&self.data)
+        } else {
+            None
+        }
+    }
+
+    // row and column maps with the data in storage order
+    pub(crate) fn raw(&self) -> (&Map, &Map, &[T]) {
+        (&self.rows, &self.columns, &self.data)
+    }
+
+    pub(crate) fn from_raw(rows: Map, columns: Map, data: Vec<T>) -> Array2d<T> {
+        Array2d {
+            rows,
+            columns,
+            data,
+        }
+    }
+
     pub fn sync_rows(&mut self, temp: &mut T) {
         for row in 0..self.height() {
             self.sync_column(row, temp);
@@ -59,6 +83,32 @@ impl<T> Array2d<T> {
     }
 }
 
+impl<T: Clone> Array2d<T> {
+    pub fn to_vec(&self) -> Vec<T> {
+        match self.as_slice() {
+            Some(data) => data.to_vec(),
+            None => {
+                let mut data = Vec::with_capacity(self.data.len());
+                for i in 0..self.height() {
+                    for j in 0..self.width() {
+                        data.push(self.at(i, j).expect("Index within own dimensions").clone());
+                    }
+                }
+                data
+            }
+        }
+    }
+
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.as_slice().is_none() {
+            self.data = self.to_vec();
+            self.rows = Left(self.height());
+            self.columns = Left(self.width());
+        }
+        &mut self.data
+    }
+}
+
 impl<T> Matrix for Array2d<T> {
     type Item = T;
 
@@ -90,6 +140,7 @@ impl<T> Matrix for Array2d<T> {
         }
     }
 
+    #[allow(clippy::useless_conversion)]
     fn swap_rows(&mut self, a: usize, b: usize) -> Option<()> {
         // let a = self.row(a)?;
         // let b = self.row(b)?;
@@ -106,6 +157,7 @@ impl<T> Matrix for Array2d<T> {
         Some(())
     }
 
+    #[allow(clippy::useless_conversion)]
     fn swap_columns(&mut self, a: usize, b: usize) -> Option<()> {
         // let a = self.column(a)?;
         // let b = self.column(b)?;
@@ -351,6 +403,22 @@ mod test {
         assert_eq!(m, m01234, "Row swap #3 (0, 3) failed");
     }
 
+    #[test]
+    fn make_contiguous_applies_permutation() {
+        let mut m = Array2d::gen(3, 2, |i, j| (i, j));
+        assert!(m.as_slice().is_some());
+        m.swap_rows(0, 2);
+        m.swap_columns(0, 1);
+        assert!(m.as_slice().is_none());
+
+        let expected = m.to_vec();
+        assert_eq!(expected[0], (2, 1));
+        let copy = m.clone();
+        assert_eq!(m.make_contiguous(), expected.as_slice());
+        assert_eq!(m, copy);
+        assert!(m.as_slice().is_some());
+    }
+
     #[test]
     fn swap_columns_ok() {
         let size = 5;
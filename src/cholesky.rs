@@ -0,0 +1,74 @@
+use crate::{Array2d, LowerTriangular, Matrix, Transposed, UpperTriangular};
+
+// A = L Lᵀ for a symmetric positive definite A, only the lower triangle of A is read
+pub fn factor<M: Matrix<Item = f64>>(m: &M) -> Option<Array2d<f64>> {
+    let n = m.height();
+    if n != m.width() {
+        return None;
+    }
+    let mut l = vec![0.0; n * n];
+    for j in 0..n {
+        let mut diag = *m.at(j, j)?;
+        for k in 0..j {
+            diag -= l[j * n + k] * l[j * n + k];
+        }
+        if diag <= 0.0 || !diag.is_finite() {
+            return None;
+        }
+        let diag = diag.sqrt();
+        l[j * n + j] = diag;
+
+        for i in (j + 1)..n {
+            let mut value = *m.at(i, j)?;
+            for k in 0..j {
+                value -= l[i * n + k] * l[j * n + k];
+            }
+            l[i * n + j] = value / diag;
+        }
+    }
+    Array2d::try_from(n, n, l).ok()
+}
+
+pub fn solve<M: Matrix<Item = f64>>(m: &M, b: &mut [f64]) -> Option<()> {
+    let l = factor(m)?;
+    LowerTriangular::new(&l).solve(b)?;
+    UpperTriangular::new(Transposed::new(&l)).solve(b)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{cholesky, Array2d, Transposed};
+
+    #[test]
+    fn factor_ok() {
+        let a = Array2d::try_from(3, 3, vec![4.0, 2.0, 2.0, 2.0, 5.0, 3.0, 2.0, 3.0, 6.0]).unwrap();
+        let l = cholesky::factor(&a).unwrap();
+        let expected = Array2d::try_from(
+            3,
+            3,
+            vec![
+                2.0, 0.0, 0.0, //
+                1.0, 2.0, 0.0, //
+                1.0, 1.0, 2.0, //
+            ],
+        )
+        .unwrap();
+        assert_eq!(l, expected);
+        assert_eq!(crate::matmul(&l, &Transposed::new(&l)).unwrap(), a);
+    }
+
+    #[test]
+    fn solve_ok() {
+        let a = Array2d::try_from(3, 3, vec![4.0, 2.0, 2.0, 2.0, 5.0, 3.0, 2.0, 3.0, 6.0]).unwrap();
+        let mut b = vec![8.0, 10.0, 11.0];
+        cholesky::solve(&a, &mut b).unwrap();
+        assert_eq!(b, vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn indefinite_is_rejected() {
+        let a = Array2d::try_from(2, 2, vec![1.0, 2.0, 2.0, 1.0]).unwrap();
+        assert!(cholesky::factor(&a).is_none());
+        assert!(cholesky::factor(&Array2d::<f64>::default(2, 3)).is_none());
+    }
+}
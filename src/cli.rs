@@ -0,0 +1,630 @@
+pub mod args;
+pub mod input;
+pub mod output;
+pub mod report;
+
+use args::Args;
+use input::Format;
+use matrices::gauss::{self, Lu, SolveError};
+use matrices::sweep::{self, Solver, Sweep};
+use matrices::{
+    diagnostics, generators, jacobi, multiply, properties, solver, Array2d, Iteratable, Matrix,
+    Options,
+};
+use output::{Document, Output};
+use std::io::Write;
+
+pub const USAGE: &str = "\
+usage: matrices <command> [options] <inputs>
+
+commands:
+  solve <A> [b]      solve A x = b, b defaults to the last column of an n x (n + 1) A
+  det <A>            determinant
+  inverse <A>        inverse matrix
+  cond <A>           condition number in the infinity norm
+  iterate <A> [b]    Gauss-Seidel iteration
+  analyze <A>        structure, dominance and convergence of A
+  sweep              time solvers on the course systems for a range of sizes
+  report             LaTeX elimination trace of the course systems, sizes 100 to 200 by default
+  bench <matmul|lu> <n>   time the blocked product or LU against the plain one on n x n
+
+inputs are paths, `-` for stdin, literals like \"[1 2; 3 4]\" or generators:
+  course:N                  the n x (n + 1) system [A | b] of the course assignment
+  hilbert:N, pascal:N, lehmer:N, frank:N, vandermonde:N, poisson1d:N, poisson2d:M
+  kahan:N[:theta]           theta 1.2 by default
+  orthogonal:N[:seed], dominant:N[:seed], spd:N[:cond[:seed]]   cond 1e3 and seed 0 by default
+
+options:
+  -f, --format <mtx|csv|text|npy|npz|binary|literal>   input format, guessed by default
+  -o, --output <text|json|latex|csv>                   text by default
+  -m, --method <name>     solve with diagonal, back, forward, thomas, cholesky, gauss or iterative
+  -e, --epsilon <e>       stopping tolerance of the iterative methods
+  --max-iterations <n>    sweeps before the iterative methods give up, 10000 by default
+  --from <n>, --to <n>, --step <n>       sizes of a sweep or report, 10 to 100 by default
+  -s, --solvers <list>    comma separated methods, auto, mixed or inverse to sweep
+  -r, --repetitions <n>   runs of every solver on every size
+  --threads <n>           sizes of a sweep run at once, 1 by default and 0 for all cores
+  -b, --block <n>         block size of a bench
+  -h, --help
+";
+
+#[derive(Debug)]
+pub enum Error {
+    // bad arguments, exits with 2
+    Usage(String),
+    // unreadable input, exits with 3
+    Input(String),
+    // writing the result failed, e.g. a closed pipe, exits with 4
+    Output(String),
+    // the computation itself failed, exits with 1
+    Failed(String),
+}
+
+impl Error {
+    pub fn code(&self) -> u8 {
+        match self {
+            Error::Failed(_) => 1,
+            Error::Usage(_) => 2,
+            Error::Input(_) => 3,
+            Error::Output(_) => 4,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Usage(message)
+            | Error::Input(message)
+            | Error::Output(message)
+            | Error::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<SolveError> for Error {
+    fn from(e: SolveError) -> Error {
+        Error::Failed(e.to_string())
+    }
+}
+
+// inputs map their own errors, so this is only reached by writes to the output
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        Error::Output(e.to_string())
+    }
+}
+
+fn matrix(args: &Args, command: &str) -> Result<Array2d<f64>, Error> {
+    let inputs = args.inputs(command, 1, 1)?;
+    input::read(&inputs[0], args.get::<Format>("format")?)
+}
+
+// A and b, or [A | b] in a single input
+fn system(args: &Args, command: &str) -> Result<(Array2d<f64>, Vec<f64>), Error> {
+    let inputs = args.inputs(command, 1, 2)?;
+    let format = args.get::<Format>("format")?;
+    let a = input::read(&inputs[0], format)?;
+    if let Some(b) = inputs.get(1) {
+        return Ok((a, input::read_vec(b, format)?));
+    }
+    let system = matrices::io::text::split_rhs(&a).ok_or_else(|| {
+        Error::Usage(format!(
+            "a {}x{} input needs a separate right-hand side",
+            a.height(),
+            a.width()
+        ))
+    })?;
+    let a = system.calculate(0).expect("Split into two parts");
+    let b = system.calculate(1).expect("Split into two parts").to_vec();
+    Ok((a, b))
+}
+
+fn solve<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only(
+        "solve",
+        &["format", "output", "method", "epsilon", "max-iterations"],
+    )?;
+    let (a, b) = system(args, "solve")?;
+    let mut options = Options {
+        method: args.get("method")?,
+        ..Options::default()
+    };
+    if let Some(epsilon) = args.get("epsilon")? {
+        options.epsilon = epsilon;
+    }
+    if let Some(max_iterations) = args.get("max-iterations")? {
+        options.max_iterations = max_iterations;
+    }
+    let report = matrices::solve(&a, &b, options)?;
+    let d = diagnostics::diagnose(&a, &report.x, &b).expect("Dimensions were checked by solve");
+    Document::new()
+        .field("x", report.x)
+        .field("method", report.method.to_string())
+        .field("reason", report.reason)
+        .optional("iterations", report.iterations)
+        .field("relative_residual", d.relative_residual)
+        .field("backward_error", d.normwise_backward_error)
+        .field("cond_estimate", d.cond)
+        .field("forward_error_bound", d.forward_error_bound)
+        .write(out, output)?;
+    Ok(())
+}
+
+fn det<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only("det", &["format", "output"])?;
+    let a = matrix(args, "det")?;
+    // a NaN pivot is not a zero one, so such input has no determinant to print
+    if a.rows().flatten().any(|v| !v.is_finite()) {
+        return Err(Error::Input(
+            "A has entries that are not finite".to_string(),
+        ));
+    }
+    let det = match Lu::new(&a) {
+        Ok(lu) => lu.det(),
+        Err(SolveError::Singular) => 0.0,
+        Err(e) => return Err(e.into()),
+    };
+    Document::new().field("det", det).write(out, output)?;
+    Ok(())
+}
+
+fn inverse<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only("inverse", &["format", "output"])?;
+    let mut a = matrix(args, "inverse")?;
+    gauss::invert(&mut a)?;
+    Document::new().field("inverse", a).write(out, output)?;
+    Ok(())
+}
+
+fn cond<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only("cond", &["format", "output"])?;
+    let a = matrix(args, "cond")?;
+    if a.height() != a.width() || a.height() == 0 {
+        return Err(SolveError::NotSquare {
+            height: a.height(),
+            width: a.width(),
+        }
+        .into());
+    }
+    Document::new()
+        .field("cond", matrices::cond(&a))
+        .field("cond_estimate", diagnostics::cond_estimate(&a))
+        .field("norm", matrices::inf_norm(&a))
+        .write(out, output)?;
+    Ok(())
+}
+
+fn iterate<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only(
+        "iterate",
+        &["format", "output", "epsilon", "max-iterations"],
+    )?;
+    let (a, b) = system(args, "iterate")?;
+    if a.height() != b.len() {
+        return Err(SolveError::Dimensions {
+            height: a.height(),
+            width: a.width(),
+            rhs: b.len(),
+        }
+        .into());
+    }
+    let epsilon = args.get("epsilon")?.unwrap_or(Options::default().epsilon);
+    let max_iterations = args
+        .get("max-iterations")?
+        .unwrap_or(Options::default().max_iterations);
+    let convergence = jacobi::convergence(&a).ok_or_else(|| {
+        Error::Failed("A is not square or has a zero on the diagonal".to_string())
+    })?;
+    if !convergence.gauss_seidel_converges() {
+        return Err(Error::Failed(format!(
+            "Gauss-Seidel does not converge on A, rho = {:e}",
+            convergence.gauss_seidel_radius
+        )));
+    }
+    let mut x = vec![0.0; b.len()];
+    // rho < 1 still leaves rounding, an epsilon below it is never reached
+    let iterations = jacobi::iterate_at_most(&mut x, epsilon, &a, &b, max_iterations)
+        .map_err(|iterations| SolveError::NotConverged { iterations })?;
+    let residual = diagnostics::relative_residual(&a, &x, &b).expect("Dimensions were checked");
+    Document::new()
+        .field("x", x)
+        .field("iterations", iterations)
+        .optional(
+            "predicted_iterations",
+            convergence.gauss_seidel_iterations(epsilon),
+        )
+        .field("rho", convergence.gauss_seidel_radius)
+        .field("relative_residual", residual)
+        .write(out, output)?;
+    Ok(())
+}
+
+fn analyze<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only("analyze", &["format", "output"])?;
+    let a = matrix(args, "analyze")?;
+    let p = properties::analyze(&a);
+    let mut document = Document::new()
+        .field("height", p.height)
+        .field("width", p.width)
+        .field("symmetric", p.symmetric)
+        .field("positive_definite", p.positive_definite)
+        .field("row_dominance", format!("{:?}", p.row_dominance))
+        .field("column_dominance", format!("{:?}", p.column_dominance))
+        .field("lower_bandwidth", p.lower_bandwidth)
+        .field("upper_bandwidth", p.upper_bandwidth)
+        .field("nonzeros", p.nonzeros)
+        .field("sparsity", p.sparsity())
+        .field("zero_diagonals", p.zero_diagonals.clone());
+    if p.square() && p.height > 0 {
+        let (method, reason) = solver::select(&p, &Options::default());
+        document = document
+            .field("cond", matrices::cond(&a))
+            .field("method", method.to_string())
+            .field("reason", reason);
+    }
+    if let Some(c) = jacobi::convergence(&a) {
+        document = document
+            .field("jacobi_radius", c.jacobi_radius)
+            .field("gauss_seidel_radius", c.gauss_seidel_radius)
+            .field("irreducibly_dominant", c.irreducibly_dominant);
+    }
+    document.write(out, output)?;
+    Ok(())
+}
+
+fn course(n: usize) -> (Array2d<f64>, Vec<f64>) {
+    (generators::course(n), generators::course_rhs(n))
+}
+
+fn sweep<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only(
+        "sweep",
+        &[
+            "output",
+            "from",
+            "to",
+            "step",
+            "solvers",
+            "repetitions",
+            "threads",
+        ],
+    )?;
+    args.inputs("sweep", 0, 0)?;
+    let defaults = Sweep::default();
+    let solvers = match args.get::<String>("solvers")? {
+        Some(list) => list
+            .split(',')
+            .map(|name| name.trim().parse::<Solver>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Error::Usage(format!("invalid --solvers: {}", e)))?,
+        None => defaults.solvers,
+    };
+    let sweep = Sweep {
+        from: args.get("from")?.unwrap_or(defaults.from),
+        to: args.get("to")?.unwrap_or(defaults.to),
+        step: args.get("step")?.unwrap_or(defaults.step),
+        solvers,
+        repetitions: args.get("repetitions")?.unwrap_or(defaults.repetitions),
+        threads: args.get("threads")?.unwrap_or(defaults.threads),
+    };
+    if sweep.step == 0 || sweep.repetitions == 0 {
+        return Err(Error::Usage(
+            "--step and --repetitions must be positive".to_string(),
+        ));
+    }
+
+    let records = sweep.records(course);
+    match output {
+        Output::Csv => {
+            sweep::write(out, records, sweep::Format::Csv)?;
+        }
+        Output::Json => {
+            sweep::write(out, records, sweep::Format::Json)?;
+        }
+        Output::Text => {
+            for r in records {
+                let outcome = match (&r.error, r.residual) {
+                    (Some(error), _) => error.clone(),
+                    (None, Some(residual)) => format!("{:.2e}", residual),
+                    (None, None) => "-".to_string(),
+                };
+                let iterations = r.iterations.map_or("-".to_string(), |i| i.to_string());
+                writeln!(
+                    out,
+                    "{} | {} | {:.6} s | {} | {} | {:.2} | {:e}",
+                    r.n,
+                    r.solver,
+                    r.elapsed.as_secs_f64(),
+                    iterations,
+                    outcome,
+                    r.cond,
+                    r.det
+                )?;
+            }
+        }
+        Output::Latex => {
+            writeln!(out, "\\begin{{tabular}}{{rlrrrrr}}")?;
+            writeln!(
+                out,
+                "$n$ & solver & seconds & iterations & residual & cond & det \\\\ \\hline"
+            )?;
+            for r in records {
+                let iterations = r.iterations.map_or("--".to_string(), |i| i.to_string());
+                let residual = r
+                    .residual
+                    .map_or("--".to_string(), |r| format!("{:.2e}", r));
+                writeln!(
+                    out,
+                    "{} & {} & {:.6} & {} & {} & {:.2} & {:.4e} \\\\",
+                    r.n,
+                    r.solver,
+                    r.elapsed.as_secs_f64(),
+                    iterations,
+                    residual,
+                    r.cond,
+                    r.det
+                )?;
+            }
+            writeln!(out, "\\end{{tabular}}")?;
+        }
+    }
+    Ok(())
+}
+
+fn sizes(args: &Args, command: &str, from: usize, to: usize) -> Result<Vec<usize>, Error> {
+    let from = args.get("from")?.unwrap_or(from);
+    let to = args.get("to")?.unwrap_or(to);
+    let step = args.get("step")?.unwrap_or(1);
+    if step == 0 {
+        return Err(Error::Usage(format!(
+            "`{}` needs a positive --step",
+            command
+        )));
+    }
+    Ok((from..=to).step_by(step).collect())
+}
+
+fn report<W: Write>(args: &Args, out: &mut W) -> Result<(), Error> {
+    args.only("report", &["from", "to", "step"])?;
+    args.inputs("report", 0, 0)?;
+    let sizes = sizes(args, "report", 100, 200)?;
+    if sizes.contains(&0) {
+        return Err(Error::Usage("`report` needs sizes from 1".to_string()));
+    }
+    let document = report::document(&sizes).map_err(|e| Error::Failed(e.to_string()))?;
+    out.write_all(document.as_bytes())?;
+    Ok(())
+}
+
+fn seconds<T>(f: impl FnOnce() -> T) -> (T, f64) {
+    let start = std::time::Instant::now();
+    let result = f();
+    (result, start.elapsed().as_secs_f64())
+}
+
+fn bench<W: Write>(args: &Args, output: Output, out: &mut W) -> Result<(), Error> {
+    args.only("bench", &["output", "block"])?;
+    let inputs = args.inputs("bench", 2, 2)?;
+    let n: usize = inputs[1]
+        .parse()
+        .map_err(|e| Error::Usage(format!("invalid size `{}`: {}", inputs[1], e)))?;
+    let block = args.get("block")?.unwrap_or(multiply::BLOCK);
+    if block == 0 {
+        return Err(Error::Usage("--block must be positive".to_string()));
+    }
+    let document = Document::new().field("n", n).field("block", block);
+    let document = match inputs[0].as_str() {
+        "matmul" => {
+            let sample = |seed: usize| {
+                Array2d::gen(n, n, |i, j| ((i * 31 + j * 17 + seed) % 23) as f64 - 11.0)
+            };
+            let (a, b) = (sample(1), sample(2));
+            let (expected, naive) = seconds(|| multiply::naive(&a, &b).unwrap());
+            let (c, blocked) = seconds(|| multiply::blocked(&a, &b, block).unwrap());
+            if c != expected {
+                return Err(Error::Failed(
+                    "the blocked product differs from the naive one".to_string(),
+                ));
+            }
+            document
+                .field("naive_seconds", naive)
+                .field("naive_gflops", multiply::gflops(n, n, n, naive))
+                .field("blocked_seconds", blocked)
+                .field("blocked_gflops", multiply::gflops(n, n, n, blocked))
+        }
+        "lu" => {
+            let a = generators::course(n);
+            let (mut expected, mut blocked) = (a.clone(), a);
+            let (plain, plain_seconds) = seconds(|| gauss::calc_l(&mut expected));
+            let (fast, blocked_seconds) = seconds(|| gauss::calc_l_blocked(&mut blocked, block));
+            if plain.is_none() || fast.is_none() {
+                return Err(SolveError::Singular.into());
+            }
+            if blocked != expected {
+                return Err(Error::Failed(
+                    "the blocked LU differs from calc_l".to_string(),
+                ));
+            }
+            document
+                .field("calc_l_seconds", plain_seconds)
+                .field("blocked_seconds", blocked_seconds)
+                .field("speedup", plain_seconds / blocked_seconds)
+        }
+        other => {
+            return Err(Error::Usage(format!(
+                "`bench` runs matmul or lu, not `{}`",
+                other
+            )))
+        }
+    };
+    document.write(out, output)?;
+    Ok(())
+}
+
+pub fn run<W: Write>(args: &[String], out: &mut W) -> Result<(), Error> {
+    let (command, rest) = args
+        .split_first()
+        .ok_or_else(|| Error::Usage("missing command".to_string()))?;
+    // after `--` even `-h` is an input
+    if matches!(command.as_str(), "-h" | "--help" | "help")
+        || rest
+            .iter()
+            .take_while(|a| *a != "--")
+            .any(|a| a == "-h" || a == "--help")
+    {
+        write!(out, "{}", USAGE)?;
+        return Ok(());
+    }
+    let args = Args::parse(rest)?;
+    let output = args.get("output")?.unwrap_or_default();
+    match command.as_str() {
+        "solve" => solve(&args, output, out),
+        "det" => det(&args, output, out),
+        "inverse" => inverse(&args, output, out),
+        "cond" => cond(&args, output, out),
+        "iterate" => iterate(&args, output, out),
+        "analyze" => analyze(&args, output, out),
+        "sweep" => sweep(&args, output, out),
+        "report" => report(&args, out),
+        "bench" => bench(&args, output, out),
+        _ => Err(Error::Usage(format!("unknown command `{}`", command))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cli::{run, Error};
+
+    fn run_with(args: &[&str]) -> Result<String, Error> {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        let mut out = vec![];
+        run(&args, &mut out).map(|()| String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn commands() {
+        let out = run_with(&["solve", "[2 1; 1 3]", "[3; 4]", "-o", "json"]).unwrap();
+        assert!(
+            out.starts_with("{\"x\":[1.0,1.0],\"method\":\"Thomas\""),
+            "{}",
+            out
+        );
+        let out = run_with(&["solve", "[2 1 3; 1 3 4]", "--method", "gauss"]).unwrap();
+        assert!(out.contains("reason = requested in options\n"), "{}", out);
+
+        assert_eq!(run_with(&["det", "[1 2; 3 4]"]).unwrap(), "det = -2.0\n");
+        assert_eq!(run_with(&["det", "[1 2; 2 4]"]).unwrap(), "det = 0.0\n");
+        assert_eq!(
+            run_with(&["inverse", "[2 0; 0 4]"]).unwrap(),
+            "inverse =\n0.5 0.0\n0.0 0.25\n"
+        );
+        assert!(run_with(&["cond", "course:5", "-o", "latex"]).is_err());
+        assert!(run_with(&["iterate", "[4 1 1; 1 5 2]", "-e", "1e-14"])
+            .unwrap()
+            .contains("relative_residual"));
+        let out = run_with(&["analyze", "[4 1; 1 3]"]).unwrap();
+        assert!(out.contains("positive_definite = true\n"), "{}", out);
+        assert!(out.contains("gauss_seidel_radius = "), "{}", out);
+
+        let out = run_with(&["sweep", "--from", "10", "--to", "12", "--step", "2"]).unwrap();
+        assert!(out.starts_with("10 | inverse | "), "{}", out);
+        assert!(
+            out.lines().next().unwrap().ends_with(" | 495.00 | 3.6288e6"),
+            "{}",
+            out
+        );
+        assert_eq!(out.lines().count(), 2);
+        let out = run_with(&[
+            "sweep",
+            "--to",
+            "10",
+            "-s",
+            "gauss,cholesky",
+            "-r2",
+            "-o",
+            "csv",
+            "--threads",
+            "2",
+        ]);
+        let out = out.unwrap();
+        assert_eq!(out.lines().count(), 5);
+        assert!(out.lines().nth(4).unwrap().starts_with("10,cholesky,1,"));
+    }
+
+    #[test]
+    fn exit_codes() {
+        let code = |args: &[&str]| run_with(args).unwrap_err().code();
+        assert_eq!(code(&[]), 2);
+        assert_eq!(code(&["transpose", "[1]"]), 2);
+        assert_eq!(code(&["det", "--method", "gauss", "[1]"]), 2);
+        assert_eq!(code(&["det", "[1]", "[2]"]), 2);
+        assert_eq!(code(&["sweep", "--solvers", "gauss,lu"]), 2);
+        assert_eq!(code(&["solve", "[1 2; 3 4]"]), 2);
+        assert_eq!(code(&["det", "no-such-file.mtx"]), 3);
+        assert_eq!(code(&["inverse", "[1 2; 2 4]"]), 1);
+        assert_eq!(code(&["iterate", "course:4"]), 1);
+        // Gauss-Seidel diverges on it, so the cap has to end the loop
+        assert_eq!(
+            code(&["solve", "[1 3; 3 1]", "[1; 1]", "-m", "iterative"]),
+            1
+        );
+        let capped = [
+            "iterate",
+            "[4 1; 1 3]",
+            "[1; 2]",
+            "-e0",
+            "--max-iterations=50",
+        ];
+        assert_eq!(code(&capped), 1);
+        assert!(run_with(&["det", "--help"]).unwrap().starts_with("usage:"));
+        // `-h` after `--` names a file
+        assert_eq!(code(&["det", "--", "-h"]), 3);
+        assert_eq!(code(&["det", "[1 NaN; 2 3]"]), 3);
+        assert_eq!(code(&["det", "[inf 1; 2 3]"]), 3);
+        // finite, but the elimination overflows
+        assert_eq!(code(&["det", "[1e308 1e308; -1e308 1e308]"]), 1);
+        assert_eq!(code(&["bench", "qr", "10"]), 2);
+        assert_eq!(code(&["report", "--from", "0", "--to", "2"]), 2);
+    }
+
+    struct ClosedPipe;
+
+    impl std::io::Write for ClosedPipe {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn output_failures_are_not_input_errors() {
+        let args = ["det".to_string(), "[1 2; 3 4]".to_string()];
+        let e = run(&args, &mut ClosedPipe).unwrap_err();
+        assert!(matches!(e, Error::Output(_)), "{:?}", e);
+        assert_eq!(e.code(), 4);
+    }
+
+    #[test]
+    fn report_and_bench() {
+        let out = run_with(&["report", "--from", "3", "--to", "5", "--step", "2"]).unwrap();
+        assert!(out.starts_with("\\documentclass"), "{}", out);
+        assert!(out.trim_end().ends_with("\\end{document}"), "{}", out);
+        let sections: Vec<_> = out.lines().filter(|l| l.starts_with("\\section")).collect();
+        assert_eq!(sections, ["\\section{ $N = 3$ }", "\\section{ $N = 5$ }"]);
+
+        let out = run_with(&["bench", "matmul", "20", "-b", "8", "-o", "json"]).unwrap();
+        assert!(
+            out.starts_with("{\"n\":20,\"block\":8,\"naive_seconds\":"),
+            "{}",
+            out
+        );
+        let out = run_with(&["bench", "lu", "12", "--block", "4"]).unwrap();
+        assert!(out.contains("speedup = "), "{}", out);
+    }
+}
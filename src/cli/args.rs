@@ -0,0 +1,161 @@
+use crate::cli::Error;
+use std::str::FromStr;
+
+// long name, short name; every option takes a value
+const OPTIONS: [(&str, Option<char>); 12] = [
+    ("format", Some('f')),
+    ("output", Some('o')),
+    ("method", Some('m')),
+    ("epsilon", Some('e')),
+    ("max-iterations", None),
+    ("from", None),
+    ("to", None),
+    ("step", None),
+    ("solvers", Some('s')),
+    ("repetitions", Some('r')),
+    ("block", Some('b')),
+    ("threads", None),
+];
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Args {
+    pub inputs: Vec<String>,
+    options: Vec<(&'static str, String)>,
+}
+
+fn lookup(arg: &str) -> Result<(&'static str, Option<&str>), Error> {
+    let found = match arg.strip_prefix("--") {
+        Some(long) => {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value)),
+                None => (long, None),
+            };
+            OPTIONS
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(n, _)| (*n, value))
+        }
+        None => {
+            let mut chars = arg[1..].chars();
+            let short = chars.next();
+            let rest = chars.as_str();
+            OPTIONS
+                .iter()
+                .find(|(_, s)| s.is_some() && *s == short)
+                .map(|(n, _)| (*n, (!rest.is_empty()).then_some(rest)))
+        }
+    };
+    found.ok_or_else(|| Error::Usage(format!("unknown option `{}`", arg)))
+}
+
+impl Args {
+    // `-` is stdin, everything after `--` is an input
+    pub fn parse(args: &[String]) -> Result<Args, Error> {
+        let mut parsed = Args::default();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            if arg == "--" {
+                parsed.inputs.extend(iter.by_ref().cloned());
+            } else if arg == "-" || !arg.starts_with('-') {
+                parsed.inputs.push(arg.clone());
+            } else {
+                let (name, value) = lookup(arg)?;
+                let value = match value {
+                    Some(value) => value.to_string(),
+                    None => iter
+                        .next()
+                        .cloned()
+                        .ok_or_else(|| Error::Usage(format!("--{} needs a value", name)))?,
+                };
+                parsed.options.push((name, value));
+            }
+        }
+        Ok(parsed)
+    }
+
+    // the last occurrence wins
+    pub fn get<T>(&self, name: &str) -> Result<Option<T>, Error>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display,
+    {
+        match self.options.iter().rev().find(|(n, _)| *n == name) {
+            Some((_, value)) => value
+                .parse()
+                .map(Some)
+                .map_err(|e| Error::Usage(format!("invalid --{} `{}`: {}", name, value, e))),
+            None => Ok(None),
+        }
+    }
+
+    pub fn only(&self, command: &str, allowed: &[&str]) -> Result<(), Error> {
+        match self.options.iter().find(|(n, _)| !allowed.contains(n)) {
+            Some((name, _)) => Err(Error::Usage(format!(
+                "`{}` does not take --{}",
+                command, name
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    pub fn inputs(&self, command: &str, min: usize, max: usize) -> Result<&[String], Error> {
+        let n = self.inputs.len();
+        if n < min || n > max {
+            let expected = if min == max {
+                min.to_string()
+            } else {
+                format!("{} to {}", min, max)
+            };
+            return Err(Error::Usage(format!(
+                "`{}` takes {} inputs, found {}",
+                command, expected, n
+            )));
+        }
+        Ok(&self.inputs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cli::args::Args;
+
+    fn parse(args: &[&str]) -> Args {
+        let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+        Args::parse(&args).unwrap()
+    }
+
+    #[test]
+    fn options_and_inputs() {
+        let args = parse(&["a.mtx", "-o", "json", "--epsilon=1e-3", "-", "-mgauss"]);
+        assert_eq!(args.inputs, vec!["a.mtx", "-"]);
+        assert_eq!(args.get::<String>("output").unwrap().unwrap(), "json");
+        assert_eq!(args.get::<f64>("epsilon").unwrap(), Some(1e-3));
+        assert_eq!(args.get::<String>("method").unwrap().unwrap(), "gauss");
+        assert_eq!(args.get::<usize>("from").unwrap(), None);
+        assert!(args.only("solve", &["output", "epsilon", "method"]).is_ok());
+        assert!(args.only("det", &["output"]).is_err());
+
+        let args = parse(&["--to", "5", "--to", "7", "--", "-x"]);
+        assert_eq!(args.get::<usize>("to").unwrap(), Some(7));
+        assert_eq!(args.inputs, vec!["-x"]);
+        assert!(args.inputs("det", 1, 1).is_ok());
+        assert!(args.inputs("solve", 2, 2).is_err());
+    }
+
+    #[test]
+    fn usage_errors() {
+        let parse = |args: &[&str]| {
+            let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+            Args::parse(&args).map(|_| ()).unwrap_err().to_string()
+        };
+        assert_eq!(parse(&["--verbose"]), "unknown option `--verbose`");
+        assert_eq!(parse(&["-x"]), "unknown option `-x`");
+        assert_eq!(parse(&["a", "--output"]), "--output needs a value");
+
+        let args = super::Args::parse(&["--from".to_string(), "ten".to_string()]).unwrap();
+        assert_eq!(
+            args.get::<usize>("from").unwrap_err().to_string(),
+            "invalid --from `ten`: invalid digit found in string"
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use crate::cli::Error;
+use matrices::io::text::Dialect;
+use matrices::io::{binary, mtx, npy};
+use matrices::{generators, Array2d, Matrix};
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Mtx,
+    Csv,
+    Text,
+    Npy,
+    Npz,
+    Binary,
+    Literal,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mtx" => Ok(Format::Mtx),
+            "csv" => Ok(Format::Csv),
+            "text" | "txt" => Ok(Format::Text),
+            "npy" => Ok(Format::Npy),
+            "npz" => Ok(Format::Npz),
+            "binary" | "bin" => Ok(Format::Binary),
+            "literal" => Ok(Format::Literal),
+            _ => Err("expected mtx, csv, text, npy, npz, binary or literal".to_string()),
+        }
+    }
+}
+
+impl Format {
+    fn from_extension(path: &str) -> Option<Format> {
+        let extension = Path::new(path).extension()?.to_str()?;
+        match extension.to_ascii_lowercase().as_str() {
+            "txt" | "dat" => Some(Format::Text),
+            extension => extension.parse().ok(),
+        }
+    }
+
+    // stdin and unknown extensions
+    fn sniff(bytes: &[u8]) -> Format {
+        let text = String::from_utf8_lossy(&bytes[..bytes.len().min(256)]);
+        if bytes.starts_with(b"%%MatrixMarket") {
+            Format::Mtx
+        } else if bytes.starts_with(b"\x93NUMPY") {
+            Format::Npy
+        } else if bytes.starts_with(b"PK\x03\x04") {
+            Format::Npz
+        } else if bytes.starts_with(b"MTRX") {
+            Format::Binary
+        } else if text.trim_start().starts_with('[') {
+            Format::Literal
+        } else if text
+            .lines()
+            .find(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .is_some_and(|line| line.contains(','))
+        {
+            Format::Csv
+        } else {
+            Format::Text
+        }
+    }
+}
+
+fn parse(bytes: &[u8], format: Format) -> Result<Array2d<f64>, String> {
+    let io = |e: matrices::io::Error| e.to_string();
+    match format {
+        Format::Mtx => mtx::read_dense(bytes).map_err(io),
+        Format::Csv => Array2d::read_csv(bytes, &Dialect::csv()).map_err(io),
+        Format::Text => Array2d::read_text(bytes).map_err(io),
+        Format::Npy => npy::read_array(bytes).map_err(io),
+        Format::Npz => {
+            let npz = npy::Npz::read(bytes).map_err(io)?;
+            let name = npz.names().next().ok_or("empty .npz archive")?;
+            npz.array(name).map_err(io)
+        }
+        Format::Binary => binary::read_array(bytes).or_else(|e| {
+            let meow = binary::read_meow::<_, f64>(bytes).map_err(|_| io(e))?;
+            Ok(Array2d::gen(meow.height(), meow.width(), |i, j| {
+                *meow.at(i, j).expect("Index within matrix dimensions")
+            }))
+        }),
+        Format::Literal => std::str::from_utf8(bytes)
+            .map_err(|e| e.to_string())?
+            .parse()
+            .map_err(|e: matrices::LiteralError| e.to_string()),
+    }
+}
+
+// `name:N[:parameters]`, see the usage; `course:N` is the n x (n + 1) system [A | b]
+// from the course assignment, every other generator gives a square A
+fn generate(source: &str) -> Option<Result<Array2d<f64>, Error>> {
+    let (name, rest) = source.split_once(':')?;
+    let max = match name {
+        "course" | "hilbert" | "pascal" | "lehmer" | "frank" | "vandermonde" | "poisson1d"
+        | "poisson2d" => 1,
+        "kahan" | "orthogonal" | "dominant" => 2,
+        "spd" => 3,
+        _ => return None,
+    };
+    let invalid = || Error::Usage(format!("invalid generator `{}`", source));
+    let parameters: Vec<&str> = rest.split(':').collect();
+    if parameters.len() > max {
+        return Some(Err(invalid()));
+    }
+    let float = |k: usize, default: f64| match parameters.get(k) {
+        Some(p) => p.parse::<f64>().map_err(|_| invalid()),
+        None => Ok(default),
+    };
+    let seed = |k: usize| match parameters.get(k) {
+        Some(p) => p.parse::<u64>().map_err(|_| invalid()),
+        None => Ok(0),
+    };
+    let generated = || -> Result<Array2d<f64>, Error> {
+        let n = parameters[0].parse::<usize>().map_err(|_| invalid())?;
+        Ok(match name {
+            "course" => generators::course_augmented(n),
+            "hilbert" => generators::hilbert(n),
+            "pascal" => generators::pascal(n),
+            "lehmer" => generators::lehmer(n),
+            "frank" => generators::frank(n),
+            // equispaced points in [0, 1]
+            "vandermonde" => {
+                let points: Vec<f64> = (0..n).map(|i| i as f64 / (n.max(2) - 1) as f64).collect();
+                generators::vandermonde(&points)
+            }
+            "poisson1d" => generators::poisson_1d(n),
+            "poisson2d" => generators::poisson_2d(n),
+            "kahan" => generators::kahan(n, float(1, 1.2)?),
+            "orthogonal" => generators::random_orthogonal(n, seed(1)?),
+            "dominant" => generators::diagonally_dominant(n, seed(1)?),
+            _ => generators::random_spd(n, float(1, 1e3)?, seed(2)?),
+        })
+    };
+    Some(generated())
+}
+
+// a path, `-` for stdin, a literal like `[1 2; 3 4]`, or a generator
+pub fn read(source: &str, format: Option<Format>) -> Result<Array2d<f64>, Error> {
+    if let Some(generated) = generate(source) {
+        return generated;
+    }
+    if format.is_none() && source.trim_start().starts_with('[') {
+        return parse(source.as_bytes(), Format::Literal)
+            .map_err(|e| Error::Input(format!("literal: {}", e)));
+    }
+    let (name, bytes) = if source == "-" {
+        let mut bytes = vec![];
+        std::io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|e| Error::Input(format!("stdin: {}", e)))?;
+        ("stdin", bytes)
+    } else {
+        let bytes =
+            std::fs::read(source).map_err(|e| Error::Input(format!("{}: {}", source, e)))?;
+        (source, bytes)
+    };
+    let format = format
+        .or_else(|| Format::from_extension(name))
+        .unwrap_or_else(|| Format::sniff(&bytes));
+    parse(&bytes, format).map_err(|e| Error::Input(format!("{}: {}", name, e)))
+}
+
+// single rows and columns
+pub fn read_vec(source: &str, format: Option<Format>) -> Result<Vec<f64>, Error> {
+    let m = read(source, format)?;
+    if m.height() != 1 && m.width() != 1 {
+        return Err(Error::Input(format!(
+            "{}: expected a vector, found a {}x{} matrix",
+            source,
+            m.height(),
+            m.width()
+        )));
+    }
+    Ok(m.to_vec())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cli::input::{self, Format};
+    use matrices::io::{binary, text};
+    use matrices::{generators, Array2d};
+
+    #[test]
+    fn formats_are_recognized() {
+        assert_eq!(Format::sniff(b"%%MatrixMarket matrix array"), Format::Mtx);
+        assert_eq!(Format::sniff(b"\x93NUMPY\x01\x00"), Format::Npy);
+        assert_eq!(Format::sniff(b"MTRX\x01\x00"), Format::Binary);
+        assert_eq!(Format::sniff(b"  [1 2; 3 4]"), Format::Literal);
+        assert_eq!(Format::sniff(b"# a, b\n1,2\n"), Format::Csv);
+        assert_eq!(Format::sniff(b"1 2\n3 4\n"), Format::Text);
+        assert_eq!(Format::from_extension("dir/a.MTX"), Some(Format::Mtx));
+        assert_eq!(Format::from_extension("a.dat"), Some(Format::Text));
+        assert_eq!(Format::from_extension("a"), None);
+    }
+
+    #[test]
+    fn literals_generators_and_files() {
+        let a = input::read("[1 2; 3 4]", None).unwrap();
+        assert_eq!(
+            a,
+            Array2d::try_from(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap()
+        );
+        assert_eq!(input::read_vec("[1; 2]", None).unwrap(), vec![1.0, 2.0]);
+        assert!(input::read_vec("[1 2; 3 4]", None).is_err());
+
+        let course = input::read("course:3", None).unwrap();
+        assert_eq!(
+            input::read("pascal:4", None).unwrap(),
+            generators::pascal(4)
+        );
+        assert_eq!(
+            input::read("spd:5:100:7", None).unwrap(),
+            generators::random_spd(5, 100.0, 7)
+        );
+        assert_eq!(
+            input::read("kahan:3", None).unwrap(),
+            generators::kahan(3, 1.2)
+        );
+        assert_eq!(input::read("hilbert:x", None).unwrap_err().code(), 2);
+        assert_eq!(input::read("frank:3:1", None).unwrap_err().code(), 2);
+        assert_eq!(
+            course.to_vec(),
+            vec![1.0, 2.0, 3.0, 1.0, -1.0, 0.0, 3.0, 2.0, -1.0, -2.0, 0.0, 3.0]
+        );
+
+        // no extension, so the format is sniffed and the Meow flattened
+        let path = std::env::temp_dir().join(format!("matrices-cli-{}", std::process::id()));
+        binary::save_meow(&path, &text::split_rhs(&course).unwrap()).unwrap();
+        let read = input::read(path.to_str().unwrap(), None);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(read.unwrap(), course);
+        assert_eq!(input::read("missing.csv", None).unwrap_err().code(), 3);
+        assert_eq!(input::read("[1 2; 3]", None).unwrap_err().code(), 3);
+    }
+}
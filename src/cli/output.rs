@@ -0,0 +1,268 @@
+use matrices::io::{json, text};
+use matrices::{Array2d, Iteratable};
+use std::io::Write;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Output {
+    #[default]
+    Text,
+    Json,
+    Latex,
+    Csv,
+}
+
+impl FromStr for Output {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Output::Text),
+            "json" => Ok(Output::Json),
+            "latex" | "tex" => Ok(Output::Latex),
+            "csv" => Ok(Output::Csv),
+            _ => Err("expected text, json, latex or csv".to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(f64),
+    Integer(usize),
+    Bool(bool),
+    Text(String),
+    Vector(Vec<f64>),
+    Indices(Vec<usize>),
+    Matrix(Array2d<f64>),
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Value {
+        Value::Number(value)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(value: usize) -> Value {
+        Value::Integer(value)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Value {
+        Value::Bool(value)
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Value {
+        Value::Text(value)
+    }
+}
+
+impl From<Vec<f64>> for Value {
+    fn from(value: Vec<f64>) -> Value {
+        Value::Vector(value)
+    }
+}
+
+impl From<Vec<usize>> for Value {
+    fn from(value: Vec<usize>) -> Value {
+        Value::Indices(value)
+    }
+}
+
+impl From<Array2d<f64>> for Value {
+    fn from(value: Array2d<f64>) -> Value {
+        Value::Matrix(value)
+    }
+}
+
+fn rows(m: &Array2d<f64>) -> Vec<Vec<f64>> {
+    m.rows().map(|row| row.cloned().collect()).collect()
+}
+
+fn list<T, F: Fn(&T) -> String>(values: &[T], separator: &str, f: F) -> String {
+    values.iter().map(f).collect::<Vec<_>>().join(separator)
+}
+
+// 1e-20 as 1 \cdot 10^{-20}
+fn latex_number(value: f64) -> String {
+    if value.is_nan() {
+        return "\\mathrm{NaN}".to_string();
+    }
+    if value.is_infinite() {
+        return if value > 0.0 { "\\infty" } else { "-\\infty" }.to_string();
+    }
+    let s = format!("{:?}", value);
+    match s.split_once('e') {
+        Some((mantissa, exponent)) => format!("{} \\cdot 10^{{{}}}", mantissa, exponent),
+        None => s,
+    }
+}
+
+fn pmatrix(rows: &[Vec<f64>]) -> String {
+    let body = list(rows, " \\\\\n", |row| {
+        list(row, " & ", |v| latex_number(*v))
+    });
+    format!("\\begin{{pmatrix}}\n{}\n\\end{{pmatrix}}", body)
+}
+
+impl Value {
+    fn text(&self) -> String {
+        match self {
+            Value::Number(v) => format!("{:?}", v),
+            Value::Integer(v) => v.to_string(),
+            Value::Bool(v) => v.to_string(),
+            Value::Text(v) => v.clone(),
+            Value::Vector(v) => format!("{:?}", v),
+            Value::Indices(v) => format!("{:?}", v),
+            // one row per line so that the output can be read back as text
+            Value::Matrix(m) => {
+                let rows = rows(m);
+                list(&rows, "\n", |row| list(row, " ", |v| format!("{:?}", v)))
+            }
+        }
+    }
+
+    fn json(&self) -> String {
+        match self {
+            Value::Number(v) => json::number(*v),
+            Value::Integer(v) => v.to_string(),
+            Value::Bool(v) => v.to_string(),
+            Value::Text(v) => json::string(v),
+            Value::Vector(v) => format!("[{}]", list(v, ",", |v| json::number(*v))),
+            Value::Indices(v) => format!("[{}]", list(v, ",", usize::to_string)),
+            Value::Matrix(m) => {
+                let rows = rows(m);
+                let rows = list(&rows, ",", |row| {
+                    format!("[{}]", list(row, ",", |v| json::number(*v)))
+                });
+                format!("[{}]", rows)
+            }
+        }
+    }
+
+    fn latex(&self) -> String {
+        match self {
+            Value::Number(v) => latex_number(*v),
+            Value::Integer(v) => v.to_string(),
+            Value::Bool(v) => format!("\\text{{{}}}", v),
+            Value::Text(v) => format!("\\text{{{}}}", v),
+            Value::Vector(v) => pmatrix(&v.iter().map(|v| vec![*v]).collect::<Vec<_>>()),
+            Value::Indices(v) => format!("\\{{{}\\}}", list(v, ", ", usize::to_string)),
+            Value::Matrix(m) => pmatrix(&rows(m)),
+        }
+    }
+}
+
+fn latex_name(name: &str) -> String {
+    if name.chars().count() == 1 {
+        name.to_string()
+    } else {
+        format!("\\text{{{}}}", name.replace('_', " "))
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Document {
+    fields: Vec<(&'static str, Value)>,
+}
+
+impl Document {
+    pub fn new() -> Document {
+        Document::default()
+    }
+
+    pub fn field<V: Into<Value>>(mut self, name: &'static str, value: V) -> Document {
+        self.fields.push((name, value.into()));
+        self
+    }
+
+    pub fn optional<V: Into<Value>>(self, name: &'static str, value: Option<V>) -> Document {
+        match value {
+            Some(value) => self.field(name, value),
+            None => self,
+        }
+    }
+
+    // JSON is a single line so that several documents form JSON Lines
+    pub fn render(&self, output: Output) -> String {
+        match output {
+            Output::Text => list(&self.fields, "", |(name, value)| match value {
+                Value::Matrix(_) => format!("{} =\n{}\n", name, value.text()),
+                _ => format!("{} = {}\n", name, value.text()),
+            }),
+            Output::Json => {
+                let fields = list(&self.fields, ",", |(name, value)| {
+                    format!("{}:{}", json::string(name), value.json())
+                });
+                format!("{{{}}}\n", fields)
+            }
+            Output::Latex => list(&self.fields, "", |(name, value)| {
+                format!("\\[ {} = {} \\]\n", latex_name(name), value.latex())
+            }),
+            // a header line and a line of values
+            Output::Csv => format!(
+                "{}\n{}\n",
+                list(&self.fields, ",", |(name, _)| name.to_string()),
+                list(&self.fields, ",", |(_, value)| {
+                    text::csv_field(&value.text())
+                })
+            ),
+        }
+    }
+
+    pub fn write<W: Write>(&self, out: &mut W, output: Output) -> std::io::Result<()> {
+        out.write_all(self.render(output).as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::cli::output::{Document, Output};
+    use matrices::Array2d;
+
+    fn document() -> Document {
+        Document::new()
+            .field("x", vec![1.0, -0.5])
+            .field("method", "say \"hi\"".to_string())
+            .field("cond", f64::INFINITY)
+            .optional("iterations", None::<usize>)
+            .field("zero_diagonals", vec![1usize])
+            .field(
+                "inverse",
+                Array2d::try_from(2, 2, vec![1.0, 2.0, 3.0, 1e-20]).unwrap(),
+            )
+    }
+
+    #[test]
+    fn text_and_json() {
+        assert_eq!(
+            document().render(Output::Text),
+            "x = [1.0, -0.5]\nmethod = say \"hi\"\ncond = inf\nzero_diagonals = [1]\ninverse =\n1.0 2.0\n3.0 1e-20\n"
+        );
+        assert_eq!(
+            document().render(Output::Json),
+            "{\"x\":[1.0,-0.5],\"method\":\"say \\\"hi\\\"\",\"cond\":null,\"zero_diagonals\":[1],\"inverse\":[[1.0,2.0],[3.0,1e-20]]}\n"
+        );
+    }
+
+    #[test]
+    fn csv() {
+        let document = Document::new()
+            .field("det", -2.0)
+            .field("x", vec![1.0, 2.0]);
+        assert_eq!(document.render(Output::Csv), "det,x\n-2.0,\"[1.0, 2.0]\"\n");
+    }
+
+    #[test]
+    fn latex() {
+        let latex = document().render(Output::Latex);
+        assert!(latex.starts_with("\\[ x = \\begin{pmatrix}\n1.0 \\\\\n-0.5\n\\end{pmatrix} \\]\n"));
+        assert!(latex.contains("\\[ \\text{cond} = \\infty \\]"));
+        assert!(latex.contains("\\text{zero diagonals} = \\{1\\}"));
+        assert!(latex.contains("3.0 & 1 \\cdot 10^{-20}\n\\end{pmatrix}"));
+    }
+}
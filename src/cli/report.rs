@@ -0,0 +1,120 @@
+use matrices::trace::{Step, Trace};
+use matrices::{gauss, generators, Array2d, Matrix};
+use rayon::prelude::*;
+use std::fmt::Write;
+
+pub trait Report {
+    fn latex(&self) -> Result<String, std::fmt::Error>;
+}
+
+impl Report for Array2d<f64> {
+    fn latex(&self) -> Result<String, std::fmt::Error> {
+        let mut s = String::new();
+
+        writeln!(s, "\\begin{{pmatrix}}")?;
+        for row in 0..self.height() {
+            let mut values = Vec::with_capacity(self.width());
+            for col in 0..self.width() {
+                values.push(format!("{:.2}", self.at(row, col).unwrap()));
+            }
+            write!(s, "{}", values.join(" & "))?;
+            if row + 1 != self.height() {
+                write!(s, " \\\\")?;
+            }
+            writeln!(s)?;
+        }
+        writeln!(s, "\\end{{pmatrix}}")?;
+
+        Ok(s)
+    }
+}
+
+impl Report for Vec<f64> {
+    fn latex(&self) -> Result<String, std::fmt::Error> {
+        let mut s = String::new();
+
+        writeln!(s, "\\begin{{pmatrix}}")?;
+        for value in self {
+            writeln!(s, "{:.2} \\\\", value)?;
+        }
+        writeln!(s, "\\end{{pmatrix}}")?;
+
+        Ok(s)
+    }
+}
+
+impl Report for Step {
+    fn latex(&self) -> Result<String, std::fmt::Error> {
+        let mut s = String::new();
+        match self {
+            Step::Swap { step, a, b } => {
+                writeln!(s, "$P _{} = E _{{ {a}, {b} }}$", step + 1)?;
+            }
+            Step::Pivot {
+                row, column, value, ..
+            } => {
+                writeln!(s, "$a _{{ {row}, {column} }} = {value}$")?;
+            }
+            Step::Eliminated { step, matrix } => {
+                writeln!(s, "A _{} = {}", step + 1, matrix.latex()?)?;
+            }
+        }
+
+        Ok(s)
+    }
+}
+
+// the elimination steps of the course matrix, `x` comes from the batch solver
+fn section(n: usize, x: &[f64]) -> Result<String, std::fmt::Error> {
+    let mut a = generators::course(n);
+    let mut s = String::new();
+    writeln!(
+        s,
+        "$A = \\{{ a _{{ i, j }} | i = \\overline {{ 0..{} }}, j = \\overline {{ 0..{} }} \\}}$\n",
+        n,
+        n + 1
+    )?;
+
+    let mut trace = Trace::new();
+    gauss::calc_l_observed(&mut a, &mut trace).expect("calc_l failed");
+
+    for step in trace.steps.iter() {
+        writeln!(s, "{}", step.latex()?)?;
+    }
+    writeln!(s, "$\\bar {{ x }} = {}$\n", x.to_vec().latex()?)?;
+    writeln!(s, "$\\Delta A = {:e}$\n", trace.det())?;
+    Ok(s)
+}
+
+// a LaTeX document with a section for every size
+pub fn document(sizes: &[usize]) -> Result<String, std::fmt::Error> {
+    let systems: Vec<_> = sizes
+        .iter()
+        .map(|&n| {
+            let b = Array2d::try_from(n, 1, generators::course_rhs(n)).unwrap();
+            (generators::course(n), b)
+        })
+        .collect();
+    let solved = gauss::solve_batch(systems, 0);
+
+    // an indexed parallel collect keeps the sizes in order
+    let sections: Vec<(usize, String)> = solved
+        .into_par_iter()
+        .map(|solved| {
+            let n = sizes[solved.index];
+            let x = solved.x.expect("The course systems are not singular");
+            (n, section(n, &x.to_vec()).unwrap())
+        })
+        .collect();
+
+    let mut s = String::new();
+    writeln!(s, r#"\documentclass[a4paper,12pt]{{article}}"#)?;
+    writeln!(s, r#"\usepackage{{amsmath}}"#)?;
+    writeln!(s, r#"\begin{{document}}"#)?;
+    for (n, section) in sections {
+        writeln!(s, "\\section{{ $N = {n}$ }}")?;
+        writeln!(s, "{section}")?;
+    }
+    writeln!(s, r#"\end{{document}}"#)?;
+    Ok(s)
+}
@@ -0,0 +1,61 @@
+use crate::Matrix;
+
+// error-free transformations, see Ogita, Rump and Oishi, "Accurate sum and dot product"
+
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let z = s - a;
+    (s, (a - (s - z)) + (b - z))
+}
+
+pub(crate) fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    (p, a.mul_add(b, -p))
+}
+
+// sum of `start + Σ x_i y_i` evaluated as if in twice the working precision
+pub(crate) fn dot2<I: Iterator<Item = (f64, f64)>>(start: f64, pairs: I) -> f64 {
+    let mut s = start;
+    let mut c = 0.0;
+    for (x, y) in pairs {
+        let (p, e) = two_prod(x, y);
+        let (t, f) = two_sum(s, p);
+        s = t;
+        c += e + f;
+    }
+    s + c
+}
+
+// b - A x with a compensated dot product per row
+pub(crate) fn residual<M: Matrix<Item = f64>>(a: &M, x: &[f64], b: &[f64]) -> Vec<f64> {
+    (0..a.height())
+        .map(|i| {
+            dot2(
+                b[i],
+                x.iter()
+                    .enumerate()
+                    .map(|(j, &x)| (-*a.at(i, j).expect("Column within matrix width"), x)),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::compensated::{dot2, residual};
+    use crate::Array2d;
+
+    #[test]
+    fn dot2_recovers_cancellation() {
+        let pairs = vec![(1e16, 1.0), (1.0, 1.0), (-1e16, 1.0)];
+        assert_eq!(pairs.iter().map(|(x, y)| x * y).sum::<f64>(), 0.0);
+        assert_eq!(dot2(0.0, pairs.into_iter()), 1.0);
+    }
+
+    #[test]
+    fn residual_is_exact_for_exact_solution() {
+        let a = Array2d::try_from(2, 2, vec![0.1, 0.2, 0.3, 0.4]).unwrap();
+        let r = residual(&a, &[1.0, 1.0], &[0.1 + 0.2, 0.3 + 0.4]);
+        assert!(r.iter().all(|r| r.abs() < 1e-16), "{:?}", r);
+    }
+}
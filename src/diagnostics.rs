@@ -0,0 +1,221 @@
+use crate::{compensated, gauss::Lu, inf_norm, Array2d, Iteratable, Matrix};
+use num::Float;
+
+fn norm(v: &[f64]) -> f64 {
+    v.iter().cloned().map(f64::abs).fold(0.0, f64::max)
+}
+
+fn fits<M: Matrix<Item = f64>>(a: &M, x: &[f64], b: &[f64]) -> bool {
+    a.width() == x.len() && a.height() == b.len()
+}
+
+// b - A x, accumulated in twice the working precision
+pub fn residual<M: Matrix<Item = f64>>(a: &M, x: &[f64], b: &[f64]) -> Option<Vec<f64>> {
+    if fits(a, x, b) {
+        Some(compensated::residual(a, x, b))
+    } else {
+        None
+    }
+}
+
+// ‖b - A x‖ / ‖b‖
+pub fn relative_residual<M: Matrix<Item = f64>>(a: &M, x: &[f64], b: &[f64]) -> Option<f64> {
+    let r = norm(&residual(a, x, b)?);
+    let b = norm(b);
+    Some(if b == 0.0 { r } else { r / b })
+}
+
+// Rigal–Gaches: ‖b - A x‖ / (‖A‖ ‖x‖ + ‖b‖)
+pub fn normwise_backward_error<M: Matrix<Item = f64>>(a: &M, x: &[f64], b: &[f64]) -> Option<f64> {
+    let r = norm(&residual(a, x, b)?);
+    let scale = inf_norm(a) * norm(x) + norm(b);
+    Some(if scale == 0.0 { r } else { r / scale })
+}
+
+// Oettli–Prager: max_i |b - A x|_i / (|A| |x| + |b|)_i
+pub fn componentwise_backward_error<M: Matrix<Item = f64>>(
+    a: &M,
+    x: &[f64],
+    b: &[f64],
+) -> Option<f64> {
+    let r = residual(a, x, b)?;
+    let mut error: f64 = 0.0;
+    for (i, row) in a.rows().enumerate() {
+        let scale = row.zip(x).map(|(a, x)| (a * x).abs()).sum::<f64>() + b[i].abs();
+        let ratio = if r[i] == 0.0 {
+            0.0
+        } else if scale == 0.0 {
+            f64::INFINITY
+        } else {
+            r[i].abs() / scale
+        };
+        error = error.max(ratio);
+    }
+    Some(error)
+}
+
+// Hager's estimate of ‖A⁻¹‖∞ = ‖A⁻ᵀ‖₁ from an existing factorization
+pub fn inverse_norm_estimate<T: Float>(lu: &Lu<T>) -> f64 {
+    let n = lu.size();
+    if n == 0 {
+        return 0.0;
+    }
+    let mut x = vec![T::one() / T::from(n).unwrap_or(T::nan()); n];
+    let mut estimate = 0.0;
+    for _ in 0..5 {
+        let mut y = x.clone();
+        lu.solve_transposed(&mut y).expect("Sizes match");
+        estimate = y.iter().map(|y| y.abs().to_f64().unwrap_or(f64::NAN)).sum();
+
+        let mut z: Vec<T> = y
+            .iter()
+            .map(|&y| if y < T::zero() { -T::one() } else { T::one() })
+            .collect();
+        lu.solve(&mut z).expect("Sizes match");
+
+        let (j, max) = z
+            .iter()
+            .map(|z| z.abs())
+            .enumerate()
+            .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Less))
+            .expect("There is at least one element");
+        let zx = z
+            .iter()
+            .zip(&x)
+            .fold(T::zero(), |sum, (&z, &x)| sum + z * x);
+        if max <= zx {
+            break;
+        }
+        x = vec![T::zero(); n];
+        x[j] = T::one();
+    }
+    estimate
+}
+
+pub fn cond_estimate(a: &Array2d<f64>) -> f64 {
+    match Lu::new(a) {
+        Ok(lu) => inf_norm(a) * inverse_norm_estimate(&lu),
+        Err(_) => f64::INFINITY,
+    }
+}
+
+// first order bound on ‖x̂ - x‖ / ‖x‖ given a normwise backward error
+pub fn forward_error_bound(cond: f64, backward_error: f64) -> f64 {
+    let product = cond * backward_error;
+    if product < 1.0 {
+        2.0 * product / (1.0 - product)
+    } else {
+        f64::INFINITY
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostics {
+    pub residual: Vec<f64>,
+    pub relative_residual: f64,
+    pub normwise_backward_error: f64,
+    pub componentwise_backward_error: f64,
+    pub cond: f64,
+    pub forward_error_bound: f64,
+}
+
+pub fn diagnose(a: &Array2d<f64>, x: &[f64], b: &[f64]) -> Option<Diagnostics> {
+    let normwise = normwise_backward_error(a, x, b)?;
+    let cond = cond_estimate(a);
+    Some(Diagnostics {
+        residual: residual(a, x, b)?,
+        relative_residual: relative_residual(a, x, b)?,
+        normwise_backward_error: normwise,
+        componentwise_backward_error: componentwise_backward_error(a, x, b)?,
+        cond,
+        forward_error_bound: forward_error_bound(cond, normwise),
+    })
+}
+
+impl std::fmt::Display for Diagnostics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "||r|| = {:e}", norm(&self.residual))?;
+        writeln!(f, "||r|| / ||b|| = {:e}", self.relative_residual)?;
+        writeln!(
+            f,
+            "normwise backward error = {:e}",
+            self.normwise_backward_error
+        )?;
+        writeln!(
+            f,
+            "componentwise backward error = {:e}",
+            self.componentwise_backward_error
+        )?;
+        writeln!(f, "cond(A) ~ {:.2}", self.cond)?;
+        writeln!(f, "forward error <= {:e}", self.forward_error_bound)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{diagnostics, gauss::Lu, generators, Array2d};
+
+    #[test]
+    fn exact_solution_has_no_error() {
+        let a = Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 3.0]).unwrap();
+        let d = diagnostics::diagnose(&a, &[1.0, 1.0], &[3.0, 4.0]).unwrap();
+        assert_eq!(d.residual, vec![0.0, 0.0]);
+        assert_eq!(d.relative_residual, 0.0);
+        assert_eq!(d.normwise_backward_error, 0.0);
+        assert_eq!(d.componentwise_backward_error, 0.0);
+        assert_eq!(d.forward_error_bound, 0.0);
+    }
+
+    #[test]
+    fn perturbed_solution_is_measured() {
+        let a = Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 3.0]).unwrap();
+        let b = [3.0, 4.0];
+        let x = [1.0 + 1e-6, 1.0];
+        let d = diagnostics::diagnose(&a, &x, &b).unwrap();
+        assert!((d.residual[0] + 2e-6).abs() < 1e-15);
+        assert!((d.relative_residual - 0.5e-6).abs() < 1e-15);
+        // |r| / (|A||x| + |b|) = 2e-6 / (3 + 2e-6 + 3)
+        assert!((d.componentwise_backward_error - 2e-6 / 6.000002).abs() < 1e-15);
+        assert!(d.forward_error_bound >= 1e-6);
+        assert!(diagnostics::diagnose(&a, &[1.0], &b).is_none());
+    }
+
+    #[test]
+    fn cond_estimate_matches_cond() {
+        for n in [2, 5, 10, 39] {
+            let a = generators::course(n);
+            let exact = crate::cond(&a);
+            let estimate = diagnostics::cond_estimate(&a);
+            assert!(estimate <= exact * (1.0 + 1e-9), "n = {}", n);
+            assert!(
+                estimate >= exact / 3.0,
+                "n = {}: {} vs {}",
+                n,
+                estimate,
+                exact
+            );
+        }
+    }
+
+    #[test]
+    fn forward_error_bound_holds() {
+        let n = 20;
+        let a = generators::course(n);
+        let exact = vec![1.0; n];
+        let b = a.try_mul_vec(&exact).unwrap();
+
+        let lu = Lu::new(&a).unwrap();
+        let mut x = b.clone();
+        lu.solve(&mut x).unwrap();
+
+        let d = diagnostics::diagnose(&a, &x, &b).unwrap();
+        let error = x.iter().map(|x| (x - 1.0).abs()).fold(0.0, f64::max);
+        assert!(
+            error <= d.forward_error_bound,
+            "{} > {}",
+            error,
+            d.forward_error_bound
+        );
+    }
+}
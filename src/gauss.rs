@@ -1,10 +1,24 @@
-use crate::{Iteratable, Matrix};
+use crate::trace::Observer;
+use crate::{compensated, multiply, Array2d, Iteratable, Matrix, Meow, UpperTriangular};
+use num::Float;
+use rayon::prelude::*;
+use rayon::ThreadPool;
+use std::sync::mpsc::Receiver;
+use std::time::{Duration, Instant};
 
 fn total_cmp(a: &(usize, f64), b: &(usize, f64)) -> std::cmp::Ordering {
     f64::total_cmp(&a.1, &b.1)
 }
 
 pub fn calc_l<M: Matrix<Item = f64>>(m: &mut M) -> Option<()> {
+    calc_l_observed(m, &mut ())
+}
+
+pub fn calc_l_observed<M, O>(m: &mut M, observer: &mut O) -> Option<()>
+where
+    M: Matrix<Item = f64>,
+    O: Observer,
+{
     let h: usize = m.height();
     let w: usize = m.width();
 
@@ -20,12 +34,14 @@ pub fn calc_l<M: Matrix<Item = f64>>(m: &mut M) -> Option<()> {
 
             if lead != i {
                 m.swap_rows(lead, i)?;
+                observer.swap(i, lead, i);
             }
 
             value
         } else {
             diag
         };
+        observer.pivot(i, i, i, value);
 
         /* less readable
         for mut row in m.irows().skip(i + 1) {
@@ -48,11 +64,107 @@ pub fn calc_l<M: Matrix<Item = f64>>(m: &mut M) -> Option<()> {
                 // *m.at_mut(row, column)? -= m.at(i, column)? * factor;
             }
         }
+        observer.eliminated(i, m);
+    }
+    Some(())
+}
+
+pub fn calc_l_blocked(m: &mut Array2d<f64>, block: usize) -> Option<()> {
+    let h = m.height();
+    let w = m.width();
+    if h > w {
+        return None;
+    }
+    let block = block.max(1);
+    let a = m.make_contiguous();
+
+    for k in (0..h).step_by(block) {
+        let end = (k + block).min(h);
+
+        // panel: unblocked elimination restricted to columns k..end,
+        // multipliers are kept below the diagonal until the very end
+        for i in k..end {
+            let diag = a[i * w + i];
+            if diag == 0.0 {
+                let (lead, _) = (i..h)
+                    .map(|row| (row, a[row * w + i]))
+                    .max_by(total_cmp)?;
+                if lead != i {
+                    for column in 0..w {
+                        a.swap(lead * w + column, i * w + column);
+                    }
+                }
+            }
+            let value = a[i * w + i];
+
+            for row in (i + 1)..h {
+                let factor = a[row * w + i] / value;
+                a[row * w + i] = factor;
+                for column in (i + 1)..end {
+                    a[row * w + column] -= a[i * w + column] * factor;
+                }
+            }
+        }
+
+        if end == w {
+            continue;
+        }
+
+        // top block row: apply the unit lower triangle of the panel
+        for i in k..end {
+            for row in (i + 1)..end {
+                let factor = a[row * w + i];
+                for column in end..w {
+                    a[row * w + column] -= a[i * w + column] * factor;
+                }
+            }
+        }
+
+        if end == h {
+            continue;
+        }
+
+        // trailing matrix: A22 -= L21 * U12
+        let width = w - end;
+        let height = h - end;
+        let mut l21 = Vec::with_capacity(height * (end - k));
+        for row in end..h {
+            l21.extend_from_slice(&a[row * w + k..row * w + end]);
+        }
+        let mut u12 = Vec::with_capacity((end - k) * width);
+        for row in k..end {
+            u12.extend_from_slice(&a[row * w + end..row * w + w]);
+        }
+        multiply::gemm(
+            &mut a[end * w + end..],
+            w,
+            &l21,
+            end - k,
+            &u12,
+            width,
+            (height, width, end - k),
+            -1.0,
+            block,
+        );
+    }
+
+    for row in 1..h {
+        for value in a[row * w..row * w + row].iter_mut() {
+            *value = 0.0;
+        }
     }
     Some(())
 }
 
 pub fn calc_u<M: Matrix<Item = f64>>(m: &mut M) -> Option<()> {
+    calc_u_observed(m, &mut ())
+}
+
+pub fn calc_u_observed<M, O>(m: &mut M, observer: &mut O) -> Option<()>
+where
+    M: Matrix<Item = f64>,
+    O: Observer,
+{
     let h: usize = m.height();
     let w: usize = m.width();
 
@@ -70,12 +182,14 @@ pub fn calc_u<M: Matrix<Item = f64>>(m: &mut M) -> Option<()> {
 
             if lead != t {
                 m.swap_rows(lead, t)?;
+                observer.swap(i, lead, t);
             }
 
             value
         } else {
             diag
         };
+        observer.pivot(i, t, t, value);
 
         /* less readable
         for (j, row) in m.irows().enumerate().rev().skip(i + 1) {
@@ -105,6 +219,83 @@ pub fn calc_u<M: Matrix<Item = f64>>(m: &mut M) -> Option<()> {
                 *m.at_mut(row, column)? -= m.at(t, column)? * factor;
             }
         }
+        observer.eliminated(i, m);
+    }
+    Some(())
+}
+
+pub fn calc_l_par(m: &mut Array2d<f64>, pool: &ThreadPool) -> Option<()> {
+    let h = m.height();
+    let w = m.width();
+    if h > w {
+        return None;
+    }
+    let a = m.make_contiguous();
+
+    for i in 0..h {
+        if a[i * w + i] == 0.0 {
+            let (lead, _) = (i..h)
+                .map(|row| (row, a[row * w + i]))
+                .max_by(total_cmp)?;
+            if lead != i {
+                for column in 0..w {
+                    a.swap(lead * w + column, i * w + column);
+                }
+            }
+        }
+
+        let (top, bottom) = a.split_at_mut((i + 1) * w);
+        let pivot = &top[i * w..];
+        let value = pivot[i];
+        pool.install(|| {
+            bottom.par_chunks_mut(w).for_each(|row| {
+                let factor = row[i] / value;
+                row[i] = 0.0;
+                for column in (i + 1)..w {
+                    row[column] -= pivot[column] * factor;
+                }
+            })
+        });
+    }
+    Some(())
+}
+
+pub fn calc_u_par(m: &mut Array2d<f64>, pool: &ThreadPool) -> Option<()> {
+    let h = m.height();
+    let w = m.width();
+    if h > w {
+        return None;
+    }
+    let a = m.make_contiguous();
+
+    for t in (0..h).rev() {
+        if a[t * w + t] == 0.0 {
+            let (lead, _) = (0..=t)
+                .rev()
+                .map(|row| (row, a[row * w + t]))
+                .max_by(total_cmp)?;
+            if lead != t {
+                for column in 0..w {
+                    a.swap(lead * w + column, t * w + column);
+                }
+            }
+        }
+
+        let (top, bottom) = a.split_at_mut(t * w);
+        let pivot = &bottom[..w];
+        let value = pivot[t];
+        pool.install(|| {
+            top.par_chunks_mut(w).enumerate().for_each(|(r, row)| {
+                let factor = row[t] / value;
+                row[t] = 0.0;
+                for column in r..t {
+                    row[column] -= pivot[column] * factor;
+                }
+                for column in (t + 1)..w {
+                    row[column] -= pivot[column] * factor;
+                }
+            })
+        });
     }
     Some(())
 }
@@ -118,9 +309,441 @@ pub fn normalize<M: Matrix<Item = f64>>(m: &mut M) {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SolveError {
+    Dimensions { height: usize, width: usize, rhs: usize },
+    NotSquare {
+        height: usize,
+        width: usize,
+    },
+    Singular,
+    // NaN or infinity in the input, or reached by overflow
+    NotFinite,
+    NotPositiveDefinite,
+    // elimination without pivoting met a zero, the system may still be regular
+    ZeroPivot,
+    ZeroDiagonal {
+        index: usize,
+    },
+    NotConverged {
+        iterations: usize,
+    },
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::Dimensions { height, width, rhs } => write!(
+                f,
+                "Cannot solve a {}x{} system with {} right-hand side rows",
+                height, width, rhs
+            ),
+            SolveError::NotSquare { height, width } => {
+                write!(f, "A {}x{} matrix is not square", height, width)
+            }
+            SolveError::Singular => write!(f, "The system is singular"),
+            SolveError::NotFinite => write!(f, "A pivot is not finite"),
+            SolveError::NotPositiveDefinite => {
+                write!(f, "The matrix is not symmetric positive definite")
+            }
+            SolveError::ZeroPivot => write!(f, "A zero pivot was met without pivoting"),
+            SolveError::ZeroDiagonal { index } => {
+                write!(f, "The diagonal element {} is zero", index)
+            }
+            SolveError::NotConverged { iterations } => {
+                write!(f, "The iteration did not converge in {} steps", iterations)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+pub fn solve(a: Array2d<f64>, b: Array2d<f64>) -> Result<Array2d<f64>, SolveError> {
+    if a.height() != a.width() || a.height() != b.height() {
+        return Err(SolveError::Dimensions {
+            height: a.height(),
+            width: a.width(),
+            rhs: b.height(),
+        });
+    }
+
+    let mut m = Meow::from(a);
+    m.eat(b).expect("Heights were checked above");
+    calc_l(&mut m).ok_or(SolveError::Singular)?;
+
+    let mut x = m.calculate(1).expect("B was eaten above");
+    UpperTriangular::new(m.part(0).expect("A is always present"))
+        .solve_columns(&mut x)
+        .ok_or(SolveError::Singular)?;
+
+    if x.to_vec().iter().all(|value| value.is_finite()) {
+        Ok(x)
+    } else {
+        Err(SolveError::Singular)
+    }
+}
+
+pub fn invert(m: &mut Array2d<f64>) -> Result<(), SolveError> {
+    let n = m.height();
+    if n != m.width() {
+        return Err(SolveError::NotSquare {
+            height: n,
+            width: m.width(),
+        });
+    }
+    let a = m.make_contiguous();
+    let mut pivots = Vec::with_capacity(n);
+
+    for k in 0..n {
+        let (lead, value) = (k..n)
+            .map(|row| (row, a[row * n + k].abs()))
+            .max_by(total_cmp)
+            .expect("There is at least one row left");
+        if value == 0.0 || !value.is_finite() {
+            return Err(SolveError::Singular);
+        }
+        if lead != k {
+            for column in 0..n {
+                a.swap(lead * n + column, k * n + column);
+            }
+        }
+        pivots.push(lead);
+
+        let (top, rest) = a.split_at_mut(k * n);
+        let (pivot, bottom) = rest.split_at_mut(n);
+
+        let value = pivot[k];
+        pivot[k] = 1.0;
+        for cell in pivot.iter_mut() {
+            *cell /= value;
+        }
+
+        for row in top.chunks_mut(n).chain(bottom.chunks_mut(n)) {
+            let factor = row[k];
+            row[k] = 0.0;
+            for (cell, p) in row.iter_mut().zip(pivot.iter()) {
+                *cell -= p * factor;
+            }
+        }
+    }
+
+    for (k, &lead) in pivots.iter().enumerate().rev() {
+        if lead != k {
+            for row in a.chunks_mut(n) {
+                row.swap(lead, k);
+            }
+        }
+    }
+    Ok(())
+}
+
+// PA = LU with partial pivoting, L (unit diagonal) and U share one matrix
+#[derive(Debug, Clone)]
+pub struct Lu<T> {
+    lu: Array2d<T>,
+    pivots: Vec<usize>,
+}
+
+impl<T: Float> Lu<T> {
+    pub fn new(a: &Array2d<T>) -> Result<Lu<T>, SolveError> {
+        let n = a.height();
+        if n != a.width() {
+            return Err(SolveError::NotSquare {
+                height: n,
+                width: a.width(),
+            });
+        }
+        let mut lu = a.clone();
+        let data = lu.make_contiguous();
+        let mut pivots = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let (lead, value) = (k..n)
+                .map(|row| (row, data[row * n + k].abs()))
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Less))
+                .expect("There is at least one row left");
+            if value == T::zero() {
+                return Err(SolveError::Singular);
+            }
+            if !value.is_finite() {
+                return Err(SolveError::NotFinite);
+            }
+            if lead != k {
+                for column in 0..n {
+                    data.swap(lead * n + column, k * n + column);
+                }
+            }
+            pivots.push(lead);
+
+            let (top, bottom) = data.split_at_mut((k + 1) * n);
+            let pivot = &top[k * n..];
+            for row in bottom.chunks_mut(n) {
+                let factor = row[k] / pivot[k];
+                row[k] = factor;
+                for column in (k + 1)..n {
+                    row[column] = row[column] - pivot[column] * factor;
+                }
+            }
+        }
+
+        Ok(Lu { lu, pivots })
+    }
+
+    pub fn size(&self) -> usize {
+        self.pivots.len()
+    }
+
+    pub fn factors(&self) -> &Array2d<T> {
+        &self.lu
+    }
+
+    pub fn det(&self) -> T {
+        let mut det = T::one();
+        for (k, &lead) in self.pivots.iter().enumerate() {
+            det = det * *self.lu.at(k, k).expect("Diagonal within matrix");
+            if lead != k {
+                det = -det;
+            }
+        }
+        det
+    }
+
+    pub fn solve(&self, b: &mut [T]) -> Option<()> {
+        let n = self.size();
+        if b.len() != n {
+            return None;
+        }
+        let lu = self.lu.as_slice().expect("Factors are kept contiguous");
+        for (k, &lead) in self.pivots.iter().enumerate() {
+            b.swap(k, lead);
+        }
+        for i in 0..n {
+            let mut value = b[i];
+            for j in 0..i {
+                value = value - lu[i * n + j] * b[j];
+            }
+            b[i] = value;
+        }
+        for i in (0..n).rev() {
+            let mut value = b[i];
+            for j in (i + 1)..n {
+                value = value - lu[i * n + j] * b[j];
+            }
+            b[i] = value / lu[i * n + i];
+        }
+        Some(())
+    }
+
+    // solves Aᵀ x = b, that is Uᵀ Lᵀ P x = b
+    pub fn solve_transposed(&self, b: &mut [T]) -> Option<()> {
+        let n = self.size();
+        if b.len() != n {
+            return None;
+        }
+        let lu = self.lu.as_slice().expect("Factors are kept contiguous");
+        for i in 0..n {
+            let mut value = b[i];
+            for j in 0..i {
+                value = value - lu[j * n + i] * b[j];
+            }
+            b[i] = value / lu[i * n + i];
+        }
+        for i in (0..n).rev() {
+            let mut value = b[i];
+            for j in (i + 1)..n {
+                value = value - lu[j * n + i] * b[j];
+            }
+            b[i] = value;
+        }
+        for (k, &lead) in self.pivots.iter().enumerate().rev() {
+            b.swap(k, lead);
+        }
+        Some(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Refinement {
+    pub steps: usize,
+    pub forward_error: f64,
+    pub converged: bool,
+}
+
+pub const MAX_REFINEMENT_STEPS: usize = 30;
+
+fn inf(v: &[f64]) -> f64 {
+    v.iter().cloned().map(f64::abs).fold(0.0, f64::max)
+}
+
+// Corrects `x` with residuals computed in twice the working precision until the
+// correction no longer shrinks at least twofold or drops below the unit roundoff
+pub fn refine<M, T>(a: &M, lu: &Lu<T>, b: &[f64], x: &mut [f64]) -> Option<Refinement>
+where
+    M: Matrix<Item = f64>,
+    T: Float,
+{
+    let n = lu.size();
+    if a.height() != n || a.width() != n || b.len() != n || x.len() != n {
+        return None;
+    }
+
+    let mut previous = f64::INFINITY;
+    let mut forward_error = f64::INFINITY;
+    for step in 1..=MAX_REFINEMENT_STEPS {
+        let residual = compensated::residual(a, x, b);
+        let mut dx: Vec<T> = residual
+            .iter()
+            .map(|&r| T::from(r).unwrap_or(T::nan()))
+            .collect();
+        lu.solve(&mut dx)?;
+        let dx: Vec<f64> = dx.iter().map(|d| d.to_f64().unwrap_or(f64::NAN)).collect();
+
+        let correction = inf(&dx);
+        if !correction.is_finite() {
+            return Some(Refinement {
+                steps: step,
+                forward_error,
+                converged: false,
+            });
+        }
+        for (x, d) in x.iter_mut().zip(&dx) {
+            *x += d;
+        }
+
+        let norm = inf(x);
+        forward_error = if norm == 0.0 {
+            correction
+        } else {
+            correction / norm
+        };
+        if forward_error <= f64::EPSILON {
+            return Some(Refinement {
+                steps: step,
+                forward_error,
+                converged: true,
+            });
+        }
+        if correction > previous / 2.0 {
+            return Some(Refinement {
+                steps: step,
+                forward_error,
+                converged: correction < previous,
+            });
+        }
+        previous = correction;
+    }
+
+    Some(Refinement {
+        steps: MAX_REFINEMENT_STEPS,
+        forward_error,
+        converged: false,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MixedSolution {
+    pub x: Vec<f64>,
+    pub refinement: Refinement,
+    pub fallback: bool,
+}
+
+// Factors a single precision copy of A and refines the solution in double precision.
+// The result is kept when its forward error is within f64 epsilon times cond(A), the
+// accuracy of a double precision factorization, which is the fallback otherwise
+pub fn solve_mixed(a: &Array2d<f64>, b: &[f64]) -> Result<MixedSolution, SolveError> {
+    if a.height() != a.width() || a.height() != b.len() {
+        return Err(SolveError::Dimensions {
+            height: a.height(),
+            width: a.width(),
+            rhs: b.len(),
+        });
+    }
+
+    if let Ok(lu) = Lu::new(&a.map(|&value| value as f32)) {
+        let mut x32: Vec<f32> = b.iter().map(|&value| value as f32).collect();
+        if lu.solve(&mut x32).is_some() {
+            let mut x: Vec<f64> = x32.into_iter().map(f64::from).collect();
+            // O(n²) on the factors at hand
+            let cond = crate::inf_norm(a) * crate::diagnostics::inverse_norm_estimate(&lu);
+            if let Some(refinement) = refine(a, &lu, b, &mut x) {
+                if refinement.converged && refinement.forward_error <= f64::EPSILON * cond {
+                    return Ok(MixedSolution {
+                        x,
+                        refinement,
+                        fallback: false,
+                    });
+                }
+            }
+        }
+    }
+
+    let lu = Lu::new(a)?;
+    let mut x = b.to_vec();
+    lu.solve(&mut x).ok_or(SolveError::Singular)?;
+    let refinement = refine(a, &lu, b, &mut x).ok_or(SolveError::Singular)?;
+    Ok(MixedSolution {
+        x,
+        refinement,
+        fallback: true,
+    })
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Solved {
+    pub index: usize,
+    pub x: Result<Array2d<f64>, SolveError>,
+    pub elapsed: Duration,
+}
+
+// `threads == 0` lets rayon pick the number of threads
+pub fn stream_batch<I>(systems: I, threads: usize) -> Receiver<Solved>
+where
+    I: IntoIterator<Item = (Array2d<f64>, Array2d<f64>)>,
+    I::IntoIter: Send + 'static,
+{
+    let systems = systems.into_iter();
+    let (tx, rx) = std::sync::mpsc::channel();
+
+    std::thread::spawn(move || {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("Failed to build a thread pool");
+        pool.install(|| {
+            systems
+                .enumerate()
+                .par_bridge()
+                .for_each_with(tx, |tx, (index, (a, b))| {
+                    let start = Instant::now();
+                    let x = solve(a, b);
+                    let elapsed = start.elapsed();
+                    // the receiver may have been dropped, there is nobody to report to then
+                    let _ = tx.send(Solved { index, x, elapsed });
+                })
+        });
+    });
+
+    rx
+}
+
+pub fn solve_batch<I>(systems: I, threads: usize) -> Vec<Solved>
+where
+    I: IntoIterator<Item = (Array2d<f64>, Array2d<f64>)>,
+    I::IntoIter: Send + 'static,
+{
+    let mut collected: Vec<Solved> = stream_batch(systems, threads).into_iter().collect();
+    collected.sort_by_key(|solved| solved.index);
+    collected
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{Array2d, Iteratable, Meow};
+    use crate::{generators, Array2d, Iteratable, Matrix, Meow};
 
     #[test]
     fn l_good_diagonal() {
@@ -250,22 +873,295 @@ mod test {
         }
     }
 
-    fn gen_a(i: usize, j: usize) -> i64 {
-        if i == 0 && j == 0 {
-            1
-        } else if i == j {
-            0
-        } else if i > j {
-            -((j + 1) as i64)
-        } else {
-            // if j > i
-            (j + 1) as i64
+    fn close(a: &Array2d<f64>, b: &Array2d<f64>) -> bool {
+        a.height() == b.height()
+            && a.width() == b.width()
+            && (0..a.height()).all(|i| {
+                (0..a.width()).all(|j| {
+                    let (x, y) = (a.at(i, j).unwrap(), b.at(i, j).unwrap());
+                    (x - y).abs() <= 1e-9 * x.abs().max(y.abs()).max(1.0)
+                })
+            })
+    }
+
+    #[test]
+    fn l_blocked_matches_l() {
+        for n in [1, 2, 5, 17, 64, 100] {
+            let a = generators::course(n);
+            let mut expected = a.clone();
+            crate::gauss::calc_l(&mut expected);
+            for block in [1, 3, 8, 32] {
+                let mut blocked = a.clone();
+                crate::gauss::calc_l_blocked(&mut blocked, block).unwrap();
+                assert_eq!(blocked, expected, "n = {}, block = {}", n, block);
+            }
+        }
+    }
+
+    #[test]
+    fn l_blocked_bad_diagonal_augmented() {
+        let mut a = Array2d::try_from(
+            4,
+            5,
+            vec![
+                1.0, 1.0, 1.0, 1.0, 1.0, //
+                1.0, 1.0, 2.0, 3.0, 1.0, //
+                2.0, 3.0, 1.0, 1.0, 1.0, //
+                1.0, 2.0, 3.0, 1.0, 1.0, //
+            ],
+        )
+        .unwrap();
+        a.swap_rows(0, 1);
+        let mut expected = a.clone();
+        crate::gauss::calc_l(&mut expected);
+        for block in [1, 2, 3] {
+            let mut blocked = a.clone();
+            crate::gauss::calc_l_blocked(&mut blocked, block).unwrap();
+            assert!(close(&blocked, &expected), "{}\n{}", blocked, expected);
+        }
+    }
+
+    #[test]
+    fn par_matches_serial() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        for n in (10..=200).step_by(10) {
+            let a = generators::course_augmented(n);
+
+            let mut serial = a.clone();
+            crate::gauss::calc_l(&mut serial).unwrap();
+            let mut parallel = a;
+            crate::gauss::calc_l_par(&mut parallel, &pool).unwrap();
+            assert_eq!(parallel, serial, "calc_l, n = {}", n);
+
+            crate::gauss::calc_u(&mut serial).unwrap();
+            crate::gauss::calc_u_par(&mut parallel, &pool).unwrap();
+            assert_eq!(parallel, serial, "calc_u, n = {}", n);
+        }
+    }
+
+    #[test]
+    fn u_par_bad_diagonal() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+        let a = Array2d::try_from(
+            4,
+            4,
+            vec![
+                1.0, 2.0, 3.0, 4.0, 2.0, 0.0, 2.0, 3.0, 3.0, 2.0, 0.0, 2.0, 4.0, 3.0, 2.0, 0.0,
+            ],
+        )
+        .unwrap();
+        let mut serial = a.clone();
+        crate::gauss::calc_u(&mut serial).unwrap();
+        let mut parallel = a;
+        crate::gauss::calc_u_par(&mut parallel, &pool).unwrap();
+        assert_eq!(parallel, serial);
+    }
+
+    #[test]
+    fn solve_matches_normalize() {
+        let n = 6;
+        let a = generators::course(n);
+        let b = Array2d::gen(n, 1, |i, _| (i + 1) as f64);
+
+        let mut m = Meow::from(a.clone());
+        m.eat(b.clone()).unwrap();
+        crate::gauss::calc_l(&mut m);
+        crate::gauss::calc_u(&mut m);
+        crate::gauss::normalize(&mut m);
+        let expected = m.calculate(1).unwrap();
+
+        let x = crate::gauss::solve(a, b).unwrap();
+        assert!(close(&x, &expected), "{}\n{}", x, expected);
+    }
+
+    #[test]
+    fn solve_reports_errors() {
+        use crate::gauss::SolveError;
+        let a = Array2d::try_from(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        let b = Array2d::try_from(2, 1, vec![1.0, 1.0]).unwrap();
+        assert_eq!(
+            crate::gauss::solve(a.clone(), b),
+            Err(SolveError::Singular)
+        );
+        let b = Array2d::try_from(3, 1, vec![1.0, 1.0, 1.0]).unwrap();
+        assert!(matches!(
+            crate::gauss::solve(a, b),
+            Err(SolveError::Dimensions { rhs: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn batch_is_ordered() {
+        let systems: Vec<_> = (1..=20)
+            .map(|n| {
+                let a = generators::course(n);
+                let b = Array2d::gen(n, 1, |i, _| (i + 1) as f64);
+                (a, b)
+            })
+            .chain(std::iter::once((
+                Array2d::default(2, 2),
+                Array2d::default(2, 1),
+            )))
+            .collect();
+
+        let solved = crate::gauss::solve_batch(systems.clone(), 4);
+        assert_eq!(solved.len(), systems.len());
+        for (index, (solved, (a, b))) in std::iter::zip(solved, systems).enumerate() {
+            assert_eq!(solved.index, index);
+            assert_eq!(solved.x, crate::gauss::solve(a, b));
+        }
+    }
+
+    #[test]
+    fn batch_streams() {
+        let systems = (1..=5).map(|n| (Array2d::<f64>::identity(n), Array2d::gen(n, 1, |_, _| 1.0)));
+        let mut seen: Vec<usize> = crate::gauss::stream_batch(systems, 2)
+            .iter()
+            .map(|solved| {
+                assert!(solved.x.is_ok());
+                solved.index
+            })
+            .collect();
+        seen.sort();
+        assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn invert_matches_inversed() {
+        for n in [1, 2, 5, 10, 39] {
+            let a = generators::course(n);
+            let expected = crate::inversed(a.clone());
+            let mut inverse = a.clone();
+            crate::gauss::invert(&mut inverse).unwrap();
+            assert!(close(&inverse, &expected), "n = {}", n);
+
+            let residual = &(&a * &inverse) - &Array2d::identity(n);
+            assert!(crate::inf_norm(&residual) < 1e-9, "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn invert_pivots() {
+        let mut a =
+            Array2d::try_from(3, 3, vec![0.0, 1.0, 2.0, 1.0, 0.0, 3.0, 4.0, -3.0, 8.0]).unwrap();
+        a.swap_columns(0, 2);
+        let original = a.clone();
+        crate::gauss::invert(&mut a).unwrap();
+        let residual = &(&original * &a) - &Array2d::identity(3);
+        assert!(crate::inf_norm(&residual) < 1e-12);
+    }
+
+    #[test]
+    fn invert_reports_singular() {
+        use crate::gauss::SolveError;
+        let mut a = Array2d::try_from(2, 2, vec![1.0, 2.0, 2.0, 4.0]).unwrap();
+        assert_eq!(crate::gauss::invert(&mut a), Err(SolveError::Singular));
+        let mut a = Array2d::<f64>::default(2, 3);
+        assert!(matches!(
+            crate::gauss::invert(&mut a),
+            Err(SolveError::NotSquare { .. })
+        ));
+    }
+
+    #[test]
+    fn lu_solves_and_det() {
+        let a =
+            Array2d::try_from(3, 3, vec![0.0, 1.0, 2.0, 1.0, 0.0, 3.0, 4.0, -3.0, 8.0]).unwrap();
+        let lu = crate::gauss::Lu::<f64>::new(&a).unwrap();
+        assert!((lu.det() - (-2.0)).abs() < 1e-12, "{}", lu.det());
+
+        let mut x = vec![3.0, 4.0, 9.0];
+        lu.solve(&mut x).unwrap();
+        for (x, e) in x.iter().zip([1.0f64, 1.0, 1.0]) {
+            assert!((x - e).abs() < 1e-12);
+        }
+        assert!(lu.solve(&mut [1.0]).is_none());
+    }
+
+    #[test]
+    fn lu_solves_transposed() {
+        let a = Array2d::try_from(3, 3, vec![0.0, 1.0, 2.0, 1.0, 0.0, 3.0, 4.0, -3.0, 8.0]).unwrap();
+        let lu = crate::gauss::Lu::new(&a).unwrap();
+        let mut x = vec![5.0, -2.0, 13.0];
+        lu.solve_transposed(&mut x).unwrap();
+        for (x, e) in x.iter().zip([1.0f64, 1.0, 1.0]) {
+            assert!((x - e).abs() < 1e-12, "{}", x);
+        }
+    }
+
+    #[test]
+    fn refine_improves_ill_conditioned() {
+        use crate::gauss::{refine, Lu};
+        let n = 39;
+        let a = generators::course(n);
+        let b = generators::course_rhs(n);
+        let lu = Lu::new(&a).unwrap();
+        let mut x = b.clone();
+        lu.solve(&mut x).unwrap();
+        let before = crate::compensated::residual(&a, &x, &b);
+
+        let refinement = refine(&a, &lu, &b, &mut x).unwrap();
+        let after = crate::compensated::residual(&a, &x, &b);
+
+        let norm = |v: &[f64]| v.iter().cloned().map(f64::abs).fold(0.0, f64::max);
+        assert!(norm(&after) <= norm(&before));
+        assert!(refinement.steps >= 1);
+        assert!(refinement.forward_error < 1e-12, "{:?}", refinement);
+    }
+
+    #[test]
+    fn mixed_recovers_double_precision() {
+        let n = 39;
+        let a = generators::course(n);
+        let b = generators::course_rhs(n);
+
+        let mixed = crate::gauss::solve_mixed(&a, &b).unwrap();
+        assert!(!mixed.fallback, "{:?}", mixed.refinement);
+
+        let lu = crate::gauss::Lu::new(&a).unwrap();
+        let mut x = b.clone();
+        lu.solve(&mut x).unwrap();
+        for (m, x) in mixed.x.iter().zip(&x) {
+            assert!((m - x).abs() <= 1e-9 * x.abs().max(1.0), "{} != {}", m, x);
+        }
+    }
+
+    #[test]
+    fn mixed_is_as_accurate_as_double_precision() {
+        let n = 50;
+        for cond in [1e2, 1e5] {
+            let a = generators::random_spd(n, cond, 1);
+            let b = a.try_mul_vec(&vec![1.0; n]).unwrap();
+
+            let mixed = crate::gauss::solve_mixed(&a, &b).unwrap();
+            assert!(!mixed.fallback, "{:?}", mixed.refinement);
+            let error = mixed.x.iter().map(|x| (x - 1.0).abs()).fold(0.0, f64::max);
+            assert!(error <= f64::EPSILON * crate::cond(&a), "{} {}", cond, error);
+        }
+    }
+
+    #[test]
+    fn mixed_falls_back_on_hilbert() {
+        let n = 10;
+        let a = Array2d::gen(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let b = a.try_mul_vec(&vec![1.0; n]).unwrap();
+
+        let mixed = crate::gauss::solve_mixed(&a, &b).unwrap();
+        assert!(mixed.fallback);
+        for x in mixed.x {
+            assert!((x - 1.0).abs() < 1e-2, "{}", x);
         }
     }
 
     #[test]
     fn l_my_example() {
-        let mut a = Array2d::gen(5, 5, |i, j| gen_a(i, j) as f64);
+        let mut a = generators::course(5);
         crate::gauss::calc_l(&mut a);
 
         let expected = Array2d::try_from(
@@ -0,0 +1,313 @@
+use crate::{Array2d, Matrix};
+
+// SplitMix64, small and good enough for test matrices
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // [0, 1) with 53 random bits
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn range(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.uniform()
+    }
+
+    // standard normal through Box-Muller
+    pub fn normal(&mut self) -> f64 {
+        let u = 1.0 - self.uniform();
+        let v = self.uniform();
+        (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+    }
+}
+
+// the matrix from the course assignment, zeros on the diagonal but det = n!
+pub fn course(n: usize) -> Array2d<f64> {
+    Array2d::gen(n, n, |i, j| {
+        if i == 0 && j == 0 {
+            1.0
+        } else if i == j {
+            0.0
+        } else if i > j {
+            -((j + 1) as f64)
+        } else {
+            (j + 1) as f64
+        }
+    })
+}
+
+pub fn course_rhs(n: usize) -> Vec<f64> {
+    (1..=n).map(|i| i as f64).collect()
+}
+
+// [A | b] as a single n x (n + 1) matrix
+pub fn course_augmented(n: usize) -> Array2d<f64> {
+    let a = course(n);
+    Array2d::gen(n, n + 1, |i, j| {
+        if j == n {
+            (i + 1) as f64
+        } else {
+            *a.at(i, j).expect("Index within matrix dimensions")
+        }
+    })
+}
+
+// every diagonal element replaced by the absolute sum of its row, so that Jacobi converges
+pub fn course_dominant(n: usize) -> Array2d<f64> {
+    let mut a = course(n);
+    for i in 0..n {
+        let sum = (0..n).map(|j| a.at(i, j).unwrap().abs()).sum();
+        *a.at_mut(i, i).unwrap() = sum;
+    }
+    a
+}
+
+pub fn hilbert(n: usize) -> Array2d<f64> {
+    Array2d::gen(n, n, |i, j| 1.0 / (i + j + 1) as f64)
+}
+
+// rows are increasing powers of the points, starting at x^0
+pub fn vandermonde(points: &[f64]) -> Array2d<f64> {
+    let n = points.len();
+    Array2d::gen(n, n, |i, j| points[i].powi(j as i32))
+}
+
+// binomial coefficients C(i + j, i), det = 1
+pub fn pascal(n: usize) -> Array2d<f64> {
+    let mut a = Array2d::gen(n, n, |_, _| 1.0);
+    for i in 1..n {
+        for j in 1..n {
+            let value = a.at(i - 1, j).unwrap() + a.at(i, j - 1).unwrap();
+            *a.at_mut(i, j).unwrap() = value;
+        }
+    }
+    a
+}
+
+pub fn lehmer(n: usize) -> Array2d<f64> {
+    Array2d::gen(n, n, |i, j| (i.min(j) + 1) as f64 / (i.max(j) + 1) as f64)
+}
+
+// upper Hessenberg with det = 1 and ill-conditioned eigenvalues
+pub fn frank(n: usize) -> Array2d<f64> {
+    Array2d::gen(n, n, |i, j| {
+        if j + 1 < i {
+            0.0
+        } else {
+            (n - i.max(j)) as f64
+        }
+    })
+}
+
+// upper triangular, row i scaled by sin(theta)^i, with -cos(theta) above the diagonal;
+// partial pivoting never swaps and hides how ill-conditioned it is
+pub fn kahan(n: usize, theta: f64) -> Array2d<f64> {
+    let (s, c) = theta.sin_cos();
+    Array2d::gen(n, n, |i, j| {
+        let scale = s.powi(i as i32);
+        match j.cmp(&i) {
+            std::cmp::Ordering::Less => 0.0,
+            std::cmp::Ordering::Equal => scale,
+            std::cmp::Ordering::Greater => -c * scale,
+        }
+    })
+}
+
+// Haar distributed, the Q of a Gaussian matrix
+pub fn random_orthogonal(n: usize, seed: u64) -> Array2d<f64> {
+    let mut rng = Rng::new(seed);
+    let mut columns: Vec<Vec<f64>> = (0..n)
+        .map(|_| (0..n).map(|_| rng.normal()).collect())
+        .collect();
+    for k in 0..n {
+        let (done, rest) = columns.split_at_mut(k);
+        let column = &mut rest[0];
+        // modified Gram-Schmidt, twice is enough
+        for _ in 0..2 {
+            for q in done.iter() {
+                let dot: f64 = q.iter().zip(column.iter()).map(|(a, b)| a * b).sum();
+                column.iter_mut().zip(q).for_each(|(x, q)| *x -= dot * q);
+            }
+        }
+        let norm = column.iter().map(|x| x * x).sum::<f64>().sqrt();
+        column.iter_mut().for_each(|x| *x /= norm);
+    }
+    Array2d::gen(n, n, |i, j| columns[j][i])
+}
+
+// Q diag(d) Q^T with d spread geometrically from 1 down to 1 / cond
+pub fn random_spd(n: usize, cond: f64, seed: u64) -> Array2d<f64> {
+    let q = random_orthogonal(n, seed);
+    let d: Vec<f64> = (0..n)
+        .map(|k| match n {
+            1 => 1.0,
+            _ => cond.powf(-(k as f64) / (n - 1) as f64),
+        })
+        .collect();
+    let mut a = Array2d::default(n, n);
+    for i in 0..n {
+        for j in i..n {
+            let value: f64 = (0..n)
+                .map(|k| q.at(i, k).unwrap() * d[k] * q.at(j, k).unwrap())
+                .sum();
+            *a.at_mut(i, j).unwrap() = value;
+            *a.at_mut(j, i).unwrap() = value;
+        }
+    }
+    a
+}
+
+// off-diagonal entries uniform in [-1, 1), strictly dominant by rows
+pub fn diagonally_dominant(n: usize, seed: u64) -> Array2d<f64> {
+    let mut rng = Rng::new(seed);
+    let data = (0..n * n)
+        .map(|k| {
+            if k / n == k % n {
+                0.0
+            } else {
+                rng.range(-1.0, 1.0)
+            }
+        })
+        .collect();
+    let mut a = Array2d::try_from(n, n, data).expect("n * n values");
+    for i in 0..n {
+        let sum: f64 = (0..n).map(|j| a.at(i, j).unwrap().abs()).sum();
+        *a.at_mut(i, i).unwrap() = sum + rng.range(1.0, 2.0);
+    }
+    a
+}
+
+pub fn poisson_1d(n: usize) -> Array2d<f64> {
+    Array2d::gen(n, n, |i, j| match i.abs_diff(j) {
+        0 => 2.0,
+        1 => -1.0,
+        _ => 0.0,
+    })
+}
+
+// five-point Laplacian on an m x m grid, numbered row by row
+pub fn poisson_2d(m: usize) -> Array2d<f64> {
+    let n = m * m;
+    Array2d::gen(n, n, |i, j| {
+        let (ri, ci) = (i / m, i % m);
+        let (rj, cj) = (j / m, j % m);
+        if i == j {
+            4.0
+        } else if (ri == rj && ci.abs_diff(cj) == 1) || (ci == cj && ri.abs_diff(rj) == 1) {
+            -1.0
+        } else {
+            0.0
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gauss::Lu;
+    use crate::generators::{self, Rng};
+    use crate::properties::{analyze, Dominance};
+    use crate::{cholesky, matrix, Matrix, Transposed};
+
+    fn det(a: &crate::Array2d<f64>) -> f64 {
+        Lu::new(a).unwrap().det()
+    }
+
+    #[test]
+    fn classic_matrices() {
+        assert_eq!(
+            generators::course_augmented(3),
+            matrix![1.0, 2.0, 3.0, 1.0; -1.0, 0.0, 3.0, 2.0; -1.0, -2.0, 0.0, 3.0]
+        );
+        assert_eq!(
+            generators::course_dominant(3),
+            matrix![6.0, 2.0, 3.0; -1.0, 4.0, 3.0; -1.0, -2.0, 3.0]
+        );
+        assert_eq!(
+            generators::pascal(4),
+            matrix![1.0, 1.0, 1.0, 1.0; 1.0, 2.0, 3.0, 4.0; 1.0, 3.0, 6.0, 10.0; 1.0, 4.0, 10.0, 20.0]
+        );
+        assert_eq!(
+            generators::frank(3),
+            matrix![3.0, 2.0, 1.0; 2.0, 2.0, 1.0; 0.0, 1.0, 1.0]
+        );
+        assert_eq!(
+            generators::lehmer(3),
+            matrix![1.0, 0.5, 1.0 / 3.0; 0.5, 1.0, 2.0 / 3.0; 1.0 / 3.0, 2.0 / 3.0, 1.0]
+        );
+        assert_eq!(
+            generators::vandermonde(&[1.0, 2.0, 3.0]),
+            matrix![1.0, 1.0, 1.0; 1.0, 2.0, 4.0; 1.0, 3.0, 9.0]
+        );
+        assert!((det(&generators::pascal(6)) - 1.0).abs() < 1e-6);
+        assert!((det(&generators::frank(6)) - 1.0).abs() < 1e-9);
+        assert!((det(&generators::hilbert(3)) - 1.0 / 2160.0).abs() < 1e-15);
+
+        let k = generators::kahan(4, 1.2);
+        let (s, c) = 1.2f64.sin_cos();
+        assert_eq!(*k.at(2, 2).unwrap(), s * s);
+        assert_eq!(*k.at(1, 3).unwrap(), -c * s);
+        assert_eq!(*k.at(3, 0).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn poisson() {
+        let p = analyze(&generators::poisson_1d(5));
+        assert!(p.tridiagonal() && p.positive_definite);
+
+        let a = generators::poisson_2d(3);
+        assert_eq!((a.height(), a.width()), (9, 9));
+        let p = analyze(&a);
+        assert!(p.symmetric && p.positive_definite);
+        assert_eq!(p.row_dominance, Dominance::Weak);
+        assert_eq!((p.lower_bandwidth, p.upper_bandwidth), (3, 3));
+        // 9 centers, 12 horizontal and vertical neighbours counted twice
+        assert_eq!(p.nonzeros, 9 + 24);
+    }
+
+    #[test]
+    fn random_matrices_are_reproducible() {
+        let mut rng = Rng::new(7);
+        let values: Vec<f64> = (0..1000).map(|_| rng.uniform()).collect();
+        assert!(values.iter().all(|v| (0.0..1.0).contains(v)));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+
+        let n = 12;
+        let q = generators::random_orthogonal(n, 1);
+        assert_eq!(q, generators::random_orthogonal(n, 1));
+        assert_ne!(q, generators::random_orthogonal(n, 2));
+        let qtq = crate::matmul(&Transposed::new(&q), &q).unwrap();
+        for i in 0..n {
+            for j in 0..n {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((qtq.at(i, j).unwrap() - expected).abs() < 1e-12);
+            }
+        }
+
+        let a = generators::random_spd(n, 1e6, 3);
+        assert!(analyze(&a).symmetric);
+        assert!(cholesky::factor(&a).is_some());
+        // the infinity norm condition number is within a factor of n of the 2-norm one
+        let cond = crate::cond(&a);
+        assert!(cond > 1e6 / n as f64 && cond < 1e6 * n as f64, "{}", cond);
+
+        let d = generators::diagonally_dominant(n, 4);
+        assert_eq!(analyze(&d).row_dominance, Dominance::Strict);
+        assert_eq!(d, generators::diagonally_dominant(n, 4));
+    }
+}
@@ -0,0 +1,133 @@
+pub mod binary;
+pub mod json;
+pub mod mtx;
+pub mod npy;
+pub mod text;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    // `line` is 1-based
+    Parse { line: usize, message: String },
+    // malformed binary input
+    Format(String),
+}
+
+impl Error {
+    pub(crate) fn parse<S: Into<String>>(line: usize, message: S) -> Error {
+        Error::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn format<S: Into<String>>(message: S) -> Error {
+        Error::Format(message.into())
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            Error::Format(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse { .. } | Error::Format(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Error {
+        Error::Io(e)
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+// plain numbers valid for every bit pattern, stored little-endian
+pub trait Element: sealed::Sealed + Copy + std::fmt::Debug + 'static {
+    // NumPy dtype without the byte order character
+    const KIND: &'static str;
+    const TAG: u8;
+    const SIZE: usize;
+
+    fn from_bytes(bytes: &[u8], big_endian: bool) -> Self;
+    fn to_le_bytes(self, out: &mut Vec<u8>);
+}
+
+macro_rules! element {
+    ($t:ty, $kind:literal, $tag:literal) => {
+        impl sealed::Sealed for $t {}
+
+        impl Element for $t {
+            const KIND: &'static str = $kind;
+            const TAG: u8 = $tag;
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn from_bytes(bytes: &[u8], big_endian: bool) -> Self {
+                let bytes = bytes.try_into().expect("Slice of SIZE bytes");
+                if big_endian {
+                    <$t>::from_be_bytes(bytes)
+                } else {
+                    <$t>::from_le_bytes(bytes)
+                }
+            }
+
+            fn to_le_bytes(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&<$t>::to_le_bytes(self));
+            }
+        }
+    };
+}
+
+element!(f32, "f4", 1);
+element!(f64, "f8", 2);
+element!(i32, "i4", 3);
+element!(i64, "i8", 4);
+
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB8_8320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+};
+
+// IEEE 802.3, as used by zip and png
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |c, &b| {
+        CRC_TABLE[((c ^ b as u32) & 0xff) as usize] ^ (c >> 8)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn crc32_check_value() {
+        assert_eq!(crate::io::crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crate::io::crc32(b""), 0);
+    }
+}
@@ -0,0 +1,509 @@
+use crate::array2d::Map;
+use crate::io::{crc32, Element, Error};
+use crate::{Array2d, Matrix, Meow};
+use either::{Left, Right};
+use std::borrow::Cow;
+use std::io::{Read, Write};
+use std::path::Path;
+
+// magic, version u16, kind u8, element tag u8, body length u64, body, crc32 of all before it;
+// every field in the body is a little-endian u64 and data is padded to 8 bytes, so element
+// data of a file loaded at an 8-aligned address is aligned as well
+const MAGIC: &[u8; 4] = b"MTRX";
+pub const VERSION: u16 = 1;
+const HEADER: usize = 16;
+const ARRAY: u8 = 0;
+const MEOW: u8 = 1;
+
+fn kind_name(kind: u8) -> &'static str {
+    match kind {
+        ARRAY => "an Array2d",
+        MEOW => "a Meow",
+        _ => "an unknown object",
+    }
+}
+
+fn tag_name(tag: u8) -> &'static str {
+    match tag {
+        1 => "f32",
+        2 => "f64",
+        3 => "i32",
+        4 => "i64",
+        _ => "unknown",
+    }
+}
+
+fn put(out: &mut Vec<u8>, value: usize) {
+    out.extend_from_slice(&(value as u64).to_le_bytes());
+}
+
+fn put_map(out: &mut Vec<u8>, map: &Map) {
+    match map {
+        Left(n) => {
+            put(out, *n);
+            put(out, 0);
+        }
+        Right(v) => {
+            put(out, v.len());
+            put(out, 1);
+            v.iter().for_each(|&i| put(out, i));
+        }
+    }
+}
+
+fn put_array<T: Element>(out: &mut Vec<u8>, a: &Array2d<T>) {
+    let (rows, columns, data) = a.raw();
+    put_map(out, rows);
+    put_map(out, columns);
+    data.iter().for_each(|x| x.to_le_bytes(out));
+    while !out.len().is_multiple_of(8) {
+        out.push(0);
+    }
+}
+
+fn finish<W: Write, T: Element>(mut writer: W, kind: u8, body: Vec<u8>) -> Result<(), Error> {
+    let mut bytes = Vec::with_capacity(HEADER + body.len() + 4);
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.push(kind);
+    bytes.push(T::TAG);
+    put(&mut bytes, body.len());
+    bytes.extend_from_slice(&body);
+    let crc = crc32(&bytes);
+    bytes.extend_from_slice(&crc.to_le_bytes());
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+pub fn write_array<W: Write, T: Element>(writer: W, a: &Array2d<T>) -> Result<(), Error> {
+    let mut body = vec![];
+    put_array(&mut body, a);
+    finish::<W, T>(writer, ARRAY, body)
+}
+
+pub fn write_meow<W: Write, T: Element>(writer: W, m: &Meow<Array2d<T>>) -> Result<(), Error> {
+    let (rows, columns, parts) = m.raw();
+    let mut body = vec![];
+    put_map(&mut body, rows);
+    put_map(&mut body, columns);
+    put(&mut body, parts.len());
+    parts.iter().for_each(|part| put_array(&mut body, part));
+    finish::<W, T>(writer, MEOW, body)
+}
+
+fn corrupted(what: &str) -> Error {
+    Error::format(format!("corrupted file: {}", what))
+}
+
+fn cast<T: Element>(bytes: &[u8]) -> Cow<'_, [T]> {
+    if cfg!(target_endian = "little") {
+        // SAFETY: `Element` is sealed and only implemented for primitive numbers,
+        // for which every bit pattern is a valid value
+        let (prefix, values, suffix) = unsafe { bytes.align_to::<T>() };
+        if prefix.is_empty() && suffix.is_empty() {
+            return Cow::Borrowed(values);
+        }
+    }
+    Cow::Owned(
+        bytes
+            .chunks_exact(T::SIZE)
+            .map(|chunk| T::from_bytes(chunk, false))
+            .collect(),
+    )
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    at: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn take(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        let end = self
+            .at
+            .checked_add(length)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| corrupted("a length points past the end of the body"))?;
+        let slice = &self.bytes[self.at..end];
+        self.at = end;
+        Ok(slice)
+    }
+
+    fn usize(&mut self) -> Result<usize, Error> {
+        let bytes = self.take(8)?.try_into().expect("Eight bytes");
+        usize::try_from(u64::from_le_bytes(bytes))
+            .map_err(|_| corrupted("a size does not fit in memory"))
+    }
+
+    fn map(&mut self, what: &str) -> Result<Map, Error> {
+        let n = self.usize()?;
+        match self.usize()? {
+            0 => Ok(Left(n)),
+            1 => {
+                let length = n
+                    .checked_mul(8)
+                    .ok_or_else(|| corrupted("a map is too long"))?;
+                let bytes = self.take(length)?;
+                let map: Vec<usize> = bytes
+                    .chunks_exact(8)
+                    .map(|b| u64::from_le_bytes(b.try_into().expect("Eight bytes")) as usize)
+                    .collect();
+                let mut seen = vec![false; n];
+                for &i in &map {
+                    if i >= n || std::mem::replace(&mut seen[i], true) {
+                        return Err(corrupted(&format!("{} map is not a permutation", what)));
+                    }
+                }
+                Ok(Right(map))
+            }
+            _ => Err(corrupted(&format!("unknown {} map flag", what))),
+        }
+    }
+
+    fn array<T: Element>(&mut self) -> Result<Borrowed<'a, T>, Error> {
+        let rows = self.map("row")?;
+        let columns = self.map("column")?;
+        let count = length(&rows)
+            .checked_mul(length(&columns))
+            .and_then(|count| count.checked_mul(T::SIZE))
+            .ok_or_else(|| corrupted("dimensions overflow"))?;
+        let data = cast(self.take(count)?);
+        self.take((8 - count % 8) % 8)?;
+        Ok(Borrowed {
+            rows,
+            columns,
+            data,
+        })
+    }
+}
+
+fn open<T: Element>(bytes: &[u8], kind: u8) -> Result<Cursor<'_>, Error> {
+    if bytes.len() < MAGIC.len() || &bytes[..MAGIC.len()] != MAGIC {
+        return Err(Error::format("not a matrix file"));
+    }
+    if bytes.len() < HEADER + 4 {
+        return Err(Error::format(format!(
+            "truncated file: {} bytes is shorter than the header",
+            bytes.len()
+        )));
+    }
+    let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+    if version != VERSION {
+        return Err(Error::format(format!(
+            "unsupported format version {}, expected {}",
+            version, VERSION
+        )));
+    }
+    let length = u64::from_le_bytes(bytes[8..16].try_into().expect("Eight bytes"));
+    let expected = usize::try_from(length)
+        .ok()
+        .and_then(|length| length.checked_add(HEADER + 4))
+        .ok_or_else(|| corrupted("body length overflows"))?;
+    if bytes.len() < expected {
+        return Err(Error::format(format!(
+            "truncated file: expected {} bytes, found {}",
+            expected,
+            bytes.len()
+        )));
+    }
+    if bytes.len() > expected {
+        return Err(Error::format(format!(
+            "unexpected {} bytes after the end of the file",
+            bytes.len() - expected
+        )));
+    }
+    let crc = u32::from_le_bytes(bytes[expected - 4..].try_into().expect("Four bytes"));
+    if crc32(&bytes[..expected - 4]) != crc {
+        return Err(Error::format("checksum mismatch, the file is corrupted"));
+    }
+    if bytes[6] != kind {
+        return Err(Error::format(format!(
+            "file holds {}, expected {}",
+            kind_name(bytes[6]),
+            kind_name(kind)
+        )));
+    }
+    if bytes[7] != T::TAG {
+        return Err(Error::format(format!(
+            "element type {} does not match the requested {}",
+            tag_name(bytes[7]),
+            tag_name(T::TAG)
+        )));
+    }
+    Ok(Cursor {
+        bytes: &bytes[..expected - 4],
+        at: HEADER,
+    })
+}
+
+fn length(map: &Map) -> usize {
+    match map {
+        Left(n) => *n,
+        Right(v) => v.len(),
+    }
+}
+
+fn lookup(map: &Map, index: usize) -> Option<usize> {
+    match map {
+        Left(n) if index < *n => Some(index),
+        Left(_) => None,
+        Right(v) => v.get(index).cloned(),
+    }
+}
+
+fn swap(map: &mut Map, a: usize, b: usize) -> Option<()> {
+    let n = length(map);
+    if a >= n || b >= n {
+        return None;
+    }
+    if let Left(n) = map {
+        *map = Right((0..*n).collect());
+    }
+    map.as_mut().right()?.swap(a, b);
+    Some(())
+}
+
+// an `Array2d` reading straight from the loaded bytes when they are suitably aligned,
+// the first `at_mut` copies the data
+#[derive(Debug, Clone)]
+pub struct Borrowed<'a, T: Element> {
+    rows: Map,
+    columns: Map,
+    data: Cow<'a, [T]>,
+}
+
+impl<T: Element> Borrowed<'_, T> {
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.data, Cow::Borrowed(_))
+    }
+
+    pub fn into_owned(self) -> Array2d<T> {
+        Array2d::from_raw(self.rows, self.columns, self.data.into_owned())
+    }
+}
+
+impl<T: Element> Matrix for Borrowed<'_, T> {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        let row = lookup(&self.rows, row)?;
+        let column = lookup(&self.columns, column)?;
+        self.data.get(row * self.width() + column)
+    }
+
+    fn at_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Item> {
+        let width = self.width();
+        let row = lookup(&self.rows, row)?;
+        let column = lookup(&self.columns, column)?;
+        self.data.to_mut().get_mut(row * width + column)
+    }
+
+    fn height(&self) -> usize {
+        length(&self.rows)
+    }
+
+    fn width(&self) -> usize {
+        length(&self.columns)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) -> Option<()> {
+        swap(&mut self.rows, a, b)
+    }
+
+    fn swap_columns(&mut self, a: usize, b: usize) -> Option<()> {
+        swap(&mut self.columns, a, b)
+    }
+}
+
+pub fn array_from_bytes<T: Element>(bytes: &[u8]) -> Result<Borrowed<'_, T>, Error> {
+    let mut cursor = open::<T>(bytes, ARRAY)?;
+    cursor.array()
+}
+
+pub fn meow_from_bytes<T: Element>(bytes: &[u8]) -> Result<Meow<Borrowed<'_, T>>, Error> {
+    let mut cursor = open::<T>(bytes, MEOW)?;
+    let rows = cursor.map("row")?;
+    let columns = cursor.map("column")?;
+    let count = cursor.usize()?;
+    let mut parts = vec![];
+    let mut width = 0;
+    for _ in 0..count {
+        let part = cursor.array::<T>()?;
+        if part.height() != length(&rows) {
+            return Err(corrupted("part heights differ"));
+        }
+        width += part.width();
+        parts.push(part);
+    }
+    if width != length(&columns) {
+        return Err(corrupted("part widths do not add up"));
+    }
+    Ok(Meow::from_raw(rows, columns, parts))
+}
+
+pub fn read_array<R: Read, T: Element>(mut reader: R) -> Result<Array2d<T>, Error> {
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes)?;
+    Ok(array_from_bytes(&bytes)?.into_owned())
+}
+
+pub fn read_meow<R: Read, T: Element>(mut reader: R) -> Result<Meow<Array2d<T>>, Error> {
+    let mut bytes = vec![];
+    reader.read_to_end(&mut bytes)?;
+    let (rows, columns, parts) = meow_from_bytes::<T>(&bytes)?.into_raw();
+    let parts = parts.into_iter().map(Borrowed::into_owned).collect();
+    Ok(Meow::from_raw(rows, columns, parts))
+}
+
+pub fn load_array<P: AsRef<Path>, T: Element>(path: P) -> Result<Array2d<T>, Error> {
+    read_array(std::fs::File::open(path)?)
+}
+
+pub fn load_meow<P: AsRef<Path>, T: Element>(path: P) -> Result<Meow<Array2d<T>>, Error> {
+    read_meow(std::fs::File::open(path)?)
+}
+
+pub fn save_array<P: AsRef<Path>, T: Element>(path: P, a: &Array2d<T>) -> Result<(), Error> {
+    write_array(std::fs::File::create(path)?, a)
+}
+
+pub fn save_meow<P: AsRef<Path>, T: Element>(path: P, m: &Meow<Array2d<T>>) -> Result<(), Error> {
+    write_meow(std::fs::File::create(path)?, m)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::io::binary;
+    use crate::{Array2d, Matrix, Meow};
+
+    fn same<A: Matrix<Item = T>, B: Matrix<Item = T>, T: PartialEq>(a: &A, b: &B) -> bool {
+        a.height() == b.height()
+            && a.width() == b.width()
+            && (0..a.height()).all(|i| (0..a.width()).all(|j| a.at(i, j) == b.at(i, j)))
+    }
+
+    fn permuted() -> Array2d<f64> {
+        let mut a = Array2d::gen(3, 5, |i, j| (i * 5 + j) as f64 / 7.0);
+        a.swap_rows(0, 2);
+        a.swap_columns(1, 4);
+        a
+    }
+
+    #[test]
+    fn array_round_trip() {
+        let a = permuted();
+        let mut bytes = vec![];
+        binary::write_array(&mut bytes, &a).unwrap();
+        let b = binary::read_array::<_, f64>(bytes.as_slice()).unwrap();
+        assert_eq!(b, a);
+        assert_eq!(b.raw(), a.raw());
+
+        let small = Array2d::gen(3, 3, |i, j| (i * 3 + j) as f32);
+        let mut bytes = vec![];
+        binary::write_array(&mut bytes, &small).unwrap();
+        assert_eq!(bytes.len() % 8, 4);
+        assert_eq!(
+            binary::read_array::<_, f32>(bytes.as_slice()).unwrap(),
+            small
+        );
+        match binary::read_array::<_, f64>(bytes.as_slice()) {
+            Err(e) => assert_eq!(
+                e.to_string(),
+                "element type f32 does not match the requested f64"
+            ),
+            Ok(_) => unreachable!(),
+        }
+
+        let ints = Array2d::gen(2, 2, |i, j| i as i64 - j as i64);
+        let mut bytes = vec![];
+        binary::write_array(&mut bytes, &ints).unwrap();
+        assert_eq!(
+            binary::read_array::<_, i64>(bytes.as_slice()).unwrap(),
+            ints
+        );
+    }
+
+    #[test]
+    fn meow_round_trip() {
+        let mut m = Meow::from(Array2d::gen(3, 3, |i, j| (i + 2 * j) as f64));
+        m.eat(Array2d::gen(3, 1, |i, _| i as f64)).ok().unwrap();
+        m.swap_rows(0, 1);
+        m.swap_columns(0, 3);
+
+        let mut bytes = vec![];
+        binary::write_meow(&mut bytes, &m).unwrap();
+        let read = binary::read_meow::<_, f64>(bytes.as_slice()).unwrap();
+        assert!(same(&read, &m));
+        assert_eq!(read.calculate(1), m.calculate(1));
+        match binary::read_array::<_, f64>(bytes.as_slice()) {
+            Err(e) => assert_eq!(e.to_string(), "file holds a Meow, expected an Array2d"),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn zero_copy_when_aligned() {
+        let a = permuted();
+        let mut bytes = vec![];
+        binary::write_array(&mut bytes, &a).unwrap();
+
+        let mut buffer = vec![0u8; bytes.len() + 8];
+        let offset = (8 - buffer.as_ptr() as usize % 8) % 8;
+        buffer[offset..offset + bytes.len()].copy_from_slice(&bytes);
+        let aligned = &buffer[offset..offset + bytes.len()];
+        let mut b = binary::array_from_bytes::<f64>(aligned).unwrap();
+        assert_eq!(b.is_borrowed(), cfg!(target_endian = "little"));
+        assert!(same(&b, &a));
+
+        *b.at_mut(0, 0).unwrap() = -1.0;
+        assert!(!b.is_borrowed());
+        assert_eq!(b.at(0, 0), Some(&-1.0));
+
+        let mut shifted = vec![0u8; bytes.len() + 8];
+        let offset = (9 - shifted.as_ptr() as usize % 8) % 8;
+        shifted[offset..offset + bytes.len()].copy_from_slice(&bytes);
+        let c = binary::array_from_bytes::<f64>(&shifted[offset..offset + bytes.len()]).unwrap();
+        assert!(!c.is_borrowed());
+        assert!(same(&c, &a));
+    }
+
+    #[test]
+    fn rejects_damaged_files() {
+        let mut bytes = vec![];
+        binary::write_array(&mut bytes, &permuted()).unwrap();
+        for length in 0..bytes.len() {
+            assert!(binary::array_from_bytes::<f64>(&bytes[..length]).is_err());
+        }
+        match binary::array_from_bytes::<f64>(&bytes[..bytes.len() - 3]) {
+            Err(e) => assert_eq!(
+                e.to_string(),
+                format!(
+                    "truncated file: expected {} bytes, found {}",
+                    bytes.len(),
+                    bytes.len() - 3
+                )
+            ),
+            Ok(_) => unreachable!(),
+        }
+
+        for at in 16..bytes.len() {
+            let mut damaged = bytes.clone();
+            damaged[at] ^= 0x10;
+            assert!(binary::array_from_bytes::<f64>(&damaged).is_err());
+        }
+        let mut damaged = bytes.clone();
+        damaged[100] ^= 1;
+        match binary::array_from_bytes::<f64>(&damaged) {
+            Err(e) => assert_eq!(e.to_string(), "checksum mismatch, the file is corrupted"),
+            Ok(_) => unreachable!(),
+        }
+
+        let mut future = bytes.clone();
+        future[4] = 2;
+        match binary::array_from_bytes::<f64>(&future) {
+            Err(e) => assert_eq!(e.to_string(), "unsupported format version 2, expected 1"),
+            Ok(_) => unreachable!(),
+        }
+        assert!(binary::array_from_bytes::<f64>(b"\x93NUMPY\x01\x00").is_err());
+    }
+}
@@ -0,0 +1,39 @@
+// JSON has no infinities or NaN
+pub fn number(value: f64) -> String {
+    if value.is_finite() {
+        format!("{:?}", value)
+    } else {
+        "null".to_string()
+    }
+}
+
+pub fn string(text: &str) -> String {
+    let mut s = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            c if (c as u32) < 0x20 => s.push_str(&format!("\\u{:04x}", c as u32)),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+    s
+}
+
+#[cfg(test)]
+mod test {
+    use crate::io::json;
+
+    #[test]
+    fn escapes() {
+        assert_eq!(json::number(-0.5), "-0.5");
+        assert_eq!(json::number(f64::NAN), "null");
+        assert_eq!(json::number(f64::NEG_INFINITY), "null");
+        assert_eq!(
+            json::string("say \"hi\"\n\\\t"),
+            "\"say \\\"hi\\\"\\n\\\\\\u0009\""
+        );
+    }
+}
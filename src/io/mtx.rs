@@ -0,0 +1,720 @@
+use crate::io::Error;
+use crate::sparse::Csr;
+use crate::{Array2d, Matrix};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Coordinate,
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Real,
+    Integer,
+    Pattern,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub format: Format,
+    pub field: Field,
+    pub symmetry: Symmetry,
+}
+
+impl Header {
+    pub fn array() -> Header {
+        Header {
+            format: Format::Array,
+            field: Field::Real,
+            symmetry: Symmetry::General,
+        }
+    }
+
+    pub fn coordinate() -> Header {
+        Header {
+            format: Format::Coordinate,
+            ..Header::array()
+        }
+    }
+}
+
+impl std::fmt::Display for Header {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = match self.format {
+            Format::Coordinate => "coordinate",
+            Format::Array => "array",
+        };
+        let field = match self.field {
+            Field::Real => "real",
+            Field::Integer => "integer",
+            Field::Pattern => "pattern",
+        };
+        let symmetry = match self.symmetry {
+            Symmetry::General => "general",
+            Symmetry::Symmetric => "symmetric",
+            Symmetry::SkewSymmetric => "skew-symmetric",
+        };
+        write!(f, "%%MatrixMarket matrix {} {} {}", format, field, symmetry)
+    }
+}
+
+fn parse_header(line: usize, text: &str) -> Result<Header, Error> {
+    let words: Vec<String> = text.split_whitespace().map(str::to_lowercase).collect();
+    if words.len() != 5 || words[0] != "%%matrixmarket" {
+        return Err(Error::parse(
+            line,
+            "expected `%%MatrixMarket matrix <format> <field> <symmetry>`",
+        ));
+    }
+    if words[1] != "matrix" {
+        return Err(Error::parse(
+            line,
+            format!("unsupported object `{}`", words[1]),
+        ));
+    }
+    let format = match words[2].as_str() {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        other => {
+            return Err(Error::parse(
+                line,
+                format!("unsupported format `{}`", other),
+            ))
+        }
+    };
+    let field = match words[3].as_str() {
+        "real" | "double" => Field::Real,
+        "integer" => Field::Integer,
+        "pattern" if format == Format::Coordinate => Field::Pattern,
+        other => return Err(Error::parse(line, format!("unsupported field `{}`", other))),
+    };
+    let symmetry = match words[4].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        other => {
+            return Err(Error::parse(
+                line,
+                format!("unsupported symmetry `{}`", other),
+            ))
+        }
+    };
+    Ok(Header {
+        format,
+        field,
+        symmetry,
+    })
+}
+
+// skips comments and blank lines, numbering every line read
+struct Lines<R> {
+    reader: R,
+    line: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    fn next_raw(&mut self) -> Result<Option<String>, Error> {
+        let mut text = String::new();
+        if self.reader.read_line(&mut text)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        Ok(Some(text))
+    }
+
+    fn next_data(&mut self) -> Result<Option<String>, Error> {
+        while let Some(text) = self.next_raw()? {
+            let trimmed = text.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(Some(trimmed.to_string()));
+            }
+        }
+        Ok(None)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(line: usize, word: &str, what: &str) -> Result<T, Error> {
+    word.parse()
+        .map_err(|_| Error::parse(line, format!("invalid {} `{}`", what, word)))
+}
+
+fn parse_value(line: usize, word: &str, field: Field) -> Result<f64, Error> {
+    match field {
+        Field::Integer => parse_number::<i64>(line, word, "integer").map(|v| v as f64),
+        _ => parse_number(line, word, "value"),
+    }
+}
+
+fn parse_index(line: usize, word: &str, size: usize, what: &str) -> Result<usize, Error> {
+    let index: usize = parse_number(line, word, what)?;
+    if index == 0 || index > size {
+        Err(Error::parse(
+            line,
+            format!("{} {} is outside 1..={}", what, index, size),
+        ))
+    } else {
+        Ok(index - 1)
+    }
+}
+
+struct Entries {
+    height: usize,
+    width: usize,
+    // of the size line, for errors about the dimensions found after parsing
+    size_line: usize,
+    // symmetric parts already mirrored, 0-based
+    triplets: Vec<(usize, usize, f64)>,
+}
+
+fn read_entries<R: BufRead>(reader: R) -> Result<(Header, Entries), Error> {
+    let mut lines = Lines { reader, line: 0 };
+    let first = lines
+        .next_raw()?
+        .ok_or_else(|| Error::parse(1, "empty file"))?;
+    let header = parse_header(1, &first)?;
+
+    let size = lines
+        .next_data()?
+        .ok_or_else(|| Error::parse(lines.line + 1, "missing size line"))?;
+    let words: Vec<&str> = size.split_whitespace().collect();
+    let expected_words = match header.format {
+        Format::Coordinate => 3,
+        Format::Array => 2,
+    };
+    if words.len() != expected_words {
+        return Err(Error::parse(
+            lines.line,
+            format!("expected {} numbers on the size line", expected_words),
+        ));
+    }
+    let size_line = lines.line;
+    let height: usize = parse_number(lines.line, words[0], "height")?;
+    let width: usize = parse_number(lines.line, words[1], "width")?;
+    if header.symmetry != Symmetry::General && height != width {
+        return Err(Error::parse(
+            lines.line,
+            format!("{}x{} matrix cannot be symmetric", height, width),
+        ));
+    }
+
+    let mut triplets = vec![];
+    let mut push = |i: usize, j: usize, value: f64| {
+        triplets.push((i, j, value));
+        if i != j {
+            match header.symmetry {
+                Symmetry::General => {}
+                Symmetry::Symmetric => triplets.push((j, i, value)),
+                Symmetry::SkewSymmetric => triplets.push((j, i, -value)),
+            }
+        }
+    };
+
+    match header.format {
+        Format::Coordinate => {
+            let count: usize = parse_number(lines.line, words[2], "entry count")?;
+            let columns = if header.field == Field::Pattern { 2 } else { 3 };
+            for read in 0..count {
+                let text = lines.next_data()?.ok_or_else(|| {
+                    Error::parse(
+                        lines.line,
+                        format!("expected {} entries, found {}", count, read),
+                    )
+                })?;
+                let words: Vec<&str> = text.split_whitespace().collect();
+                if words.len() != columns {
+                    return Err(Error::parse(
+                        lines.line,
+                        format!("expected {} fields, found {}", columns, words.len()),
+                    ));
+                }
+                let i = parse_index(lines.line, words[0], height, "row")?;
+                let j = parse_index(lines.line, words[1], width, "column")?;
+                let value = match header.field {
+                    Field::Pattern => 1.0,
+                    field => parse_value(lines.line, words[2], field)?,
+                };
+                match header.symmetry {
+                    Symmetry::Symmetric if i < j => {
+                        return Err(Error::parse(
+                            lines.line,
+                            "entry above the diagonal of a symmetric matrix",
+                        ))
+                    }
+                    Symmetry::SkewSymmetric if i <= j => {
+                        return Err(Error::parse(
+                            lines.line,
+                            "entry on or above the diagonal of a skew-symmetric matrix",
+                        ))
+                    }
+                    _ => {}
+                }
+                push(i, j, value);
+            }
+        }
+        Format::Array => {
+            // only the stored triangle for symmetric matrices, these are square
+            let count = match header.symmetry {
+                Symmetry::General => height.checked_mul(width),
+                Symmetry::Symmetric => height
+                    .checked_add(1)
+                    .and_then(|n| n.checked_mul(height))
+                    .map(|n| n / 2),
+                Symmetry::SkewSymmetric => {
+                    height.checked_mul(height.saturating_sub(1)).map(|n| n / 2)
+                }
+            }
+            .ok_or_else(|| Error::parse(size_line, format!("{}x{} is too large", height, width)))?;
+            // column-major, walked as the values arrive since the size line is not trusted
+            let symmetry = header.symmetry;
+            let cells = (0..width)
+                .flat_map(move |j| {
+                    let start = match symmetry {
+                        Symmetry::General => 0,
+                        Symmetry::Symmetric => j,
+                        Symmetry::SkewSymmetric => j + 1,
+                    };
+                    (start..height).map(move |i| (i, j))
+                })
+                .take(count);
+            for (read, (i, j)) in cells.enumerate() {
+                let text = lines.next_data()?.ok_or_else(|| {
+                    Error::parse(
+                        size_line,
+                        format!("expected {} values, found {}", count, read),
+                    )
+                })?;
+                let mut words = text.split_whitespace();
+                let word = words.next().expect("Data lines are not blank");
+                if words.next().is_some() {
+                    return Err(Error::parse(lines.line, "expected one value per line"));
+                }
+                push(i, j, parse_value(lines.line, word, header.field)?);
+            }
+        }
+    }
+
+    if lines.next_data()?.is_some() {
+        return Err(Error::parse(
+            lines.line,
+            "unexpected data after the last entry",
+        ));
+    }
+
+    Ok((
+        header,
+        Entries {
+            height,
+            width,
+            size_line,
+            triplets,
+        },
+    ))
+}
+
+pub fn read_dense<R: BufRead>(reader: R) -> Result<Array2d<f64>, Error> {
+    let (_, entries) = read_entries(reader)?;
+    if entries.height.checked_mul(entries.width).is_none() {
+        return Err(Error::parse(
+            entries.size_line,
+            format!("{}x{} is too large", entries.height, entries.width),
+        ));
+    }
+    let mut a = Array2d::default(entries.height, entries.width);
+    for (i, j, value) in entries.triplets {
+        *a.at_mut(i, j).expect("Indices were checked while parsing") += value;
+    }
+    Ok(a)
+}
+
+pub fn read_sparse<R: BufRead>(reader: R) -> Result<Csr<f64>, Error> {
+    let (_, entries) = read_entries(reader)?;
+    Ok(
+        Csr::from_triplets(entries.height, entries.width, entries.triplets)
+            .expect("Indices were checked while parsing"),
+    )
+}
+
+pub fn read_header<R: BufRead>(mut reader: R) -> Result<Header, Error> {
+    let mut first = String::new();
+    reader.read_line(&mut first)?;
+    parse_header(1, &first)
+}
+
+pub fn load_dense<P: AsRef<Path>>(path: P) -> Result<Array2d<f64>, Error> {
+    read_dense(BufReader::new(std::fs::File::open(path)?))
+}
+
+pub fn load_sparse<P: AsRef<Path>>(path: P) -> Result<Csr<f64>, Error> {
+    read_sparse(BufReader::new(std::fs::File::open(path)?))
+}
+
+fn invalid(message: String) -> Error {
+    Error::Io(std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        message,
+    ))
+}
+
+fn stored(symmetry: Symmetry, i: usize, j: usize) -> bool {
+    match symmetry {
+        Symmetry::General => true,
+        Symmetry::Symmetric => i >= j,
+        Symmetry::SkewSymmetric => i > j,
+    }
+}
+
+fn check<M: Matrix<Item = f64>>(m: &M, header: Header) -> Result<(), Error> {
+    let at = |i: usize, j: usize| *m.at(i, j).expect("Index within matrix dimensions");
+    if header.symmetry != Symmetry::General && m.height() != m.width() {
+        return Err(invalid(format!(
+            "{}x{} matrix cannot be written as {:?}",
+            m.height(),
+            m.width(),
+            header.symmetry
+        )));
+    }
+    for i in 0..m.height() {
+        for j in 0..m.width() {
+            let value = at(i, j);
+            let mirrored = match header.symmetry {
+                Symmetry::General => value,
+                Symmetry::Symmetric => at(j, i),
+                Symmetry::SkewSymmetric => -at(j, i),
+            };
+            if value != mirrored {
+                return Err(invalid(format!(
+                    "entry ({}, {}) breaks {:?} symmetry",
+                    i + 1,
+                    j + 1,
+                    header.symmetry
+                )));
+            }
+            if header.field == Field::Integer && value.fract() != 0.0 {
+                return Err(invalid(format!(
+                    "entry ({}, {}) = {} is not an integer",
+                    i + 1,
+                    j + 1,
+                    value
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_value<W: Write>(writer: &mut W, field: Field, value: f64) -> std::io::Result<()> {
+    match field {
+        Field::Real => write!(writer, "{}", value),
+        Field::Integer => write!(writer, "{}", value as i64),
+        Field::Pattern => Ok(()),
+    }
+}
+
+fn write_entries<W: Write, M: Matrix<Item = f64>>(
+    writer: W,
+    m: &M,
+    header: Header,
+    nonzeros: Vec<(usize, usize, f64)>,
+) -> Result<(), Error> {
+    let mut writer = BufWriter::new(writer);
+    writeln!(writer, "{}", header)?;
+    match header.format {
+        Format::Coordinate => {
+            let nonzeros: Vec<_> = nonzeros
+                .into_iter()
+                .filter(|&(i, j, _)| stored(header.symmetry, i, j))
+                .collect();
+            writeln!(writer, "{} {} {}", m.height(), m.width(), nonzeros.len())?;
+            for (i, j, value) in nonzeros {
+                write!(writer, "{} {}", i + 1, j + 1)?;
+                if header.field != Field::Pattern {
+                    write!(writer, " ")?;
+                    write_value(&mut writer, header.field, value)?;
+                }
+                writeln!(writer)?;
+            }
+        }
+        Format::Array => {
+            if header.field == Field::Pattern {
+                return Err(invalid("array format cannot store a pattern".to_string()));
+            }
+            writeln!(writer, "{} {}", m.height(), m.width())?;
+            for j in 0..m.width() {
+                for i in (0..m.height()).filter(|&i| stored(header.symmetry, i, j)) {
+                    let value = *m.at(i, j).expect("Index within matrix dimensions");
+                    write_value(&mut writer, header.field, value)?;
+                    writeln!(writer)?;
+                }
+            }
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write<W: Write, M: Matrix<Item = f64>>(
+    writer: W,
+    m: &M,
+    header: Header,
+) -> Result<(), Error> {
+    check(m, header)?;
+    let mut nonzeros = vec![];
+    for i in 0..m.height() {
+        for j in 0..m.width() {
+            let value = *m.at(i, j).expect("Index within matrix dimensions");
+            if value != 0.0 {
+                nonzeros.push((i, j, value));
+            }
+        }
+    }
+    write_entries(writer, m, header, nonzeros)
+}
+
+// only visits the stored entries when checking symmetry
+pub fn write_sparse<W: Write>(writer: W, m: &Csr<f64>, header: Header) -> Result<(), Error> {
+    if header.format == Format::Array {
+        return write(writer, m, header);
+    }
+    if header.symmetry != Symmetry::General && m.height() != m.width() {
+        return check(m, header);
+    }
+    let nonzeros: Vec<_> = m.entries().map(|(i, j, &value)| (i, j, value)).collect();
+    for &(i, j, value) in &nonzeros {
+        let mirrored = match header.symmetry {
+            Symmetry::General => value,
+            Symmetry::Symmetric => *m.at(j, i).expect("Square matrix"),
+            Symmetry::SkewSymmetric => -*m.at(j, i).expect("Square matrix"),
+        };
+        if value != mirrored || (header.field == Field::Integer && value.fract() != 0.0) {
+            return Err(invalid(format!(
+                "entry ({}, {}) = {} cannot be written as {}",
+                i + 1,
+                j + 1,
+                value,
+                header
+            )));
+        }
+    }
+    write_entries(writer, m, header, nonzeros)
+}
+
+pub fn save<P: AsRef<Path>, M: Matrix<Item = f64>>(
+    path: P,
+    m: &M,
+    header: Header,
+) -> Result<(), Error> {
+    write(std::fs::File::create(path)?, m, header)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::io::mtx::{self, Field, Format, Header, Symmetry};
+    use crate::io::Error;
+    use crate::sparse::Csr;
+    use crate::{Array2d, Matrix};
+
+    fn line_of(result: Result<Array2d<f64>, Error>) -> usize {
+        match result {
+            Err(Error::Parse { line, .. }) => line,
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn coordinate_general() {
+        let text = "%%MatrixMarket matrix coordinate real general
+% a comment
+
+3 3 4
+1 1 1.5
+2 3 -2e1
+3 1 4
+1 1 0.5
+";
+        let a = mtx::read_dense(text.as_bytes()).unwrap();
+        let expected =
+            Array2d::try_from(3, 3, vec![2.0, 0.0, 0.0, 0.0, 0.0, -20.0, 4.0, 0.0, 0.0]).unwrap();
+        assert_eq!(a, expected);
+        let s = mtx::read_sparse(text.as_bytes()).unwrap();
+        assert_eq!(s.nonzeros(), 3);
+        assert_eq!(s.to_dense(), expected);
+    }
+
+    #[test]
+    fn symmetric_and_skew() {
+        let text = "%%MatrixMarket matrix coordinate integer symmetric
+2 2 2
+1 1 3
+2 1 -1
+";
+        let a = mtx::read_dense(text.as_bytes()).unwrap();
+        assert_eq!(
+            a,
+            Array2d::try_from(2, 2, vec![3.0, -1.0, -1.0, 0.0]).unwrap()
+        );
+
+        let text = "%%MatrixMarket matrix array real skew-symmetric
+3 3
+1
+2
+3
+";
+        let a = mtx::read_dense(text.as_bytes()).unwrap();
+        let expected = Array2d::try_from(
+            3,
+            3,
+            vec![
+                0.0, -1.0, -2.0, //
+                1.0, 0.0, -3.0, //
+                2.0, 3.0, 0.0, //
+            ],
+        )
+        .unwrap();
+        assert_eq!(a, expected);
+    }
+
+    #[test]
+    fn array_and_pattern() {
+        let text = "%%MatrixMarket matrix array real general
+2 3
+1
+4
+2
+5
+3
+6
+";
+        let a = mtx::read_dense(text.as_bytes()).unwrap();
+        assert_eq!(
+            a,
+            Array2d::try_from(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+        );
+
+        let text = "%%MatrixMarket matrix coordinate pattern general\n2 2 1\n2 1\n";
+        let s = mtx::read_sparse(text.as_bytes()).unwrap();
+        assert_eq!(s.at(1, 0), Some(&1.0));
+        assert_eq!(s.nonzeros(), 1);
+    }
+
+    #[test]
+    fn errors_carry_line_numbers() {
+        let header = "%%MatrixMarket matrix coordinate real general\n";
+        assert_eq!(line_of(mtx::read_dense("%%Matrix\n".as_bytes())), 1);
+        assert_eq!(
+            line_of(mtx::read_dense(
+                "%%MatrixMarket matrix coordinate complex general\n".as_bytes()
+            )),
+            1
+        );
+        let text = format!("{}% comment\n2 2 2\n1 1 1\n1 x 2\n", header);
+        assert_eq!(line_of(mtx::read_dense(text.as_bytes())), 5);
+        let text = format!("{}2 2 2\n1 1 1\n3 1 2\n", header);
+        assert_eq!(line_of(mtx::read_dense(text.as_bytes())), 4);
+        let text = format!("{}2 2 3\n1 1 1\n2 1 2\n", header);
+        assert_eq!(line_of(mtx::read_dense(text.as_bytes())), 4);
+        let text = format!("{}2 2 1\n1 1 1\n\n2 2 2\n", header);
+        assert_eq!(line_of(mtx::read_dense(text.as_bytes())), 5);
+        let text = "%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1\n";
+        assert_eq!(line_of(mtx::read_dense(text.as_bytes())), 3);
+        let text = "%%MatrixMarket matrix coordinate integer general\n1 1 1\n1 1 1.5\n";
+        assert_eq!(line_of(mtx::read_dense(text.as_bytes())), 3);
+
+        match mtx::read_dense(text.as_bytes()) {
+            Err(e) => assert_eq!(e.to_string(), "line 3: invalid integer `1.5`"),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn hostile_size_lines() {
+        // nothing is allocated for the values the size line promises
+        let text = "%%MatrixMarket matrix array real general\n100000 100000\n1\n";
+        match mtx::read_dense(text.as_bytes()) {
+            Err(e) => assert_eq!(
+                e.to_string(),
+                "line 2: expected 10000000000 values, found 1"
+            ),
+            Ok(_) => unreachable!(),
+        }
+        let text = "%%MatrixMarket matrix array real symmetric\n18446744073709551615 18446744073709551615\n";
+        assert_eq!(line_of(mtx::read_dense(text.as_bytes())), 2);
+
+        let text = "%%MatrixMarket matrix coordinate real general\n4294967296 4294967296 0\n";
+        match mtx::read_dense(text.as_bytes()) {
+            Err(e) => assert_eq!(e.to_string(), "line 2: 4294967296x4294967296 is too large"),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn write_round_trip() {
+        let a = Array2d::try_from(3, 3, vec![4.0, 1.0, 0.0, 1.0, 5.0, 2.5, 0.0, 2.5, 6.0]).unwrap();
+        for format in [Format::Coordinate, Format::Array] {
+            for symmetry in [Symmetry::General, Symmetry::Symmetric] {
+                let header = Header {
+                    format,
+                    field: Field::Real,
+                    symmetry,
+                };
+                let mut buffer = vec![];
+                mtx::write(&mut buffer, &a, header).unwrap();
+                assert_eq!(mtx::read_header(buffer.as_slice()).unwrap(), header);
+                assert_eq!(mtx::read_dense(buffer.as_slice()).unwrap(), a);
+            }
+        }
+
+        let mut buffer = vec![];
+        mtx::write_sparse(&mut buffer, &Csr::from_matrix(&a), Header::coordinate()).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        assert!(text.starts_with("%%MatrixMarket matrix coordinate real general\n3 3 7\n"));
+        assert_eq!(
+            mtx::read_sparse(text.as_bytes()).unwrap(),
+            Csr::from_matrix(&a)
+        );
+
+        let skew = Array2d::try_from(2, 2, vec![0.0, -3.0, 3.0, 0.0]).unwrap();
+        let header = Header {
+            symmetry: Symmetry::SkewSymmetric,
+            field: Field::Integer,
+            ..Header::array()
+        };
+        let mut buffer = vec![];
+        mtx::write(&mut buffer, &skew, header).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer.clone()).unwrap(),
+            "%%MatrixMarket matrix array integer skew-symmetric\n2 2\n3\n"
+        );
+        assert_eq!(mtx::read_dense(buffer.as_slice()).unwrap(), skew);
+    }
+
+    #[test]
+    fn write_rejects_mismatched_header() {
+        let a = Array2d::try_from(2, 2, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let symmetric = Header {
+            symmetry: Symmetry::Symmetric,
+            ..Header::coordinate()
+        };
+        assert!(mtx::write(vec![], &a, symmetric).is_err());
+        assert!(mtx::write_sparse(vec![], &Csr::from_matrix(&a), symmetric).is_err());
+        let pattern = Header {
+            field: Field::Pattern,
+            ..Header::array()
+        };
+        assert!(mtx::write(vec![], &a, pattern).is_err());
+        let integer = Header {
+            field: Field::Integer,
+            ..Header::array()
+        };
+        assert!(mtx::write(vec![], &a.map(|x| x / 3.0), integer).is_err());
+    }
+}
@@ -0,0 +1,739 @@
+use crate::io::{crc32, Element, Error};
+use crate::{Array2d, Matrix};
+use std::io::{Read, Write};
+use std::path::Path;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Order {
+    C,
+    Fortran,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub descr: String,
+    pub order: Order,
+    pub shape: Vec<usize>,
+}
+
+// just enough of Python literal syntax for the header dictionary
+struct Literal<'a> {
+    text: &'a [u8],
+    at: usize,
+}
+
+impl<'a> Literal<'a> {
+    fn error(&self, expected: &str) -> Error {
+        Error::format(format!(
+            "malformed .npy header: expected {} at offset {}",
+            expected, self.at
+        ))
+    }
+
+    fn skip(&mut self) {
+        while self.at < self.text.len() && self.text[self.at].is_ascii_whitespace() {
+            self.at += 1;
+        }
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        self.skip();
+        if self.text.get(self.at) == Some(&c) {
+            self.at += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> Result<(), Error> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("`{}`", c as char)))
+        }
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        self.skip();
+        let quote = match self.text.get(self.at) {
+            Some(&q) if q == b'\'' || q == b'"' => q,
+            _ => return Err(self.error("a string")),
+        };
+        let start = self.at + 1;
+        let end = start
+            + self.text[start..]
+                .iter()
+                .position(|&c| c == quote)
+                .ok_or_else(|| self.error("a closing quote"))?;
+        self.at = end + 1;
+        String::from_utf8(self.text[start..end].to_vec()).map_err(|_| self.error("ASCII"))
+    }
+
+    fn word(&mut self) -> &'a [u8] {
+        self.skip();
+        let start = self.at;
+        while self.at < self.text.len() && self.text[self.at].is_ascii_alphanumeric() {
+            self.at += 1;
+        }
+        &self.text[start..self.at]
+    }
+
+    fn boolean(&mut self) -> Result<bool, Error> {
+        match self.word() {
+            b"True" => Ok(true),
+            b"False" => Ok(false),
+            _ => Err(self.error("True or False")),
+        }
+    }
+
+    fn tuple(&mut self) -> Result<Vec<usize>, Error> {
+        self.expect(b'(')?;
+        let mut items = vec![];
+        while !self.eat(b')') {
+            let word = self.word();
+            let item = std::str::from_utf8(word)
+                .ok()
+                .and_then(|w| w.trim_end_matches('L').parse().ok())
+                .ok_or_else(|| self.error("a dimension"))?;
+            items.push(item);
+            if !self.eat(b',') {
+                self.expect(b')')?;
+                break;
+            }
+        }
+        Ok(items)
+    }
+}
+
+fn parse_header(text: &[u8]) -> Result<Header, Error> {
+    let mut literal = Literal { text, at: 0 };
+    let (mut descr, mut order, mut shape) = (None, None, None);
+    literal.expect(b'{')?;
+    while !literal.eat(b'}') {
+        let key = literal.string()?;
+        literal.expect(b':')?;
+        match key.as_str() {
+            "descr" => descr = Some(literal.string()?),
+            "fortran_order" => {
+                order = Some(if literal.boolean()? {
+                    Order::Fortran
+                } else {
+                    Order::C
+                })
+            }
+            "shape" => shape = Some(literal.tuple()?),
+            other => {
+                return Err(Error::format(format!(
+                    "unknown key `{}` in .npy header",
+                    other
+                )))
+            }
+        }
+        if !literal.eat(b',') {
+            literal.expect(b'}')?;
+            break;
+        }
+    }
+    match (descr, order, shape) {
+        (Some(descr), Some(order), Some(shape)) => Ok(Header {
+            descr,
+            order,
+            shape,
+        }),
+        _ => Err(Error::format(
+            ".npy header lacks one of descr, fortran_order, shape",
+        )),
+    }
+}
+
+fn read_exact<R: Read>(reader: &mut R, bytes: &mut [u8], what: &str) -> Result<(), Error> {
+    reader.read_exact(bytes).map_err(|e| match e.kind() {
+        std::io::ErrorKind::UnexpectedEof => Error::format(format!("truncated .npy {}", what)),
+        _ => Error::Io(e),
+    })
+}
+
+pub fn read_header<R: Read>(reader: &mut R) -> Result<Header, Error> {
+    let mut preamble = [0; 8];
+    read_exact(reader, &mut preamble, "preamble")?;
+    if &preamble[..6] != MAGIC {
+        return Err(Error::format("not a .npy file"));
+    }
+    let length = match preamble[6] {
+        1 => {
+            let mut length = [0; 2];
+            read_exact(reader, &mut length, "header")?;
+            u16::from_le_bytes(length) as usize
+        }
+        2 | 3 => {
+            let mut length = [0; 4];
+            read_exact(reader, &mut length, "header")?;
+            u32::from_le_bytes(length) as usize
+        }
+        version => {
+            return Err(Error::format(format!(
+                "unsupported .npy version {}.{}",
+                version, preamble[7]
+            )))
+        }
+    };
+    let mut text = vec![0; length];
+    read_exact(reader, &mut text, "header")?;
+    parse_header(&text)
+}
+
+fn read_data<R: Read, T: Element>(reader: &mut R) -> Result<(Header, Vec<T>), Error> {
+    let header = read_header(reader)?;
+    let (endian, kind) = header.descr.split_at(header.descr.len().min(1));
+    let big_endian = match endian {
+        "<" | "=" | "|" => false,
+        ">" => true,
+        _ => return Err(Error::format(format!("unknown dtype `{}`", header.descr))),
+    };
+    if kind != T::KIND {
+        return Err(Error::format(format!(
+            "dtype `{}` does not match the requested `{}`",
+            header.descr,
+            T::KIND
+        )));
+    }
+    // an empty dimension makes the others irrelevant, however large
+    let length = if header.shape.contains(&0) {
+        Some(0)
+    } else {
+        header
+            .shape
+            .iter()
+            .try_fold(T::SIZE, |length, &n| length.checked_mul(n))
+    }
+    .ok_or_else(|| Error::format(format!("shape {:?} is too large", header.shape)))?;
+    // the header is untrusted, so the buffer only grows with the data that is really there
+    let mut bytes = Vec::new();
+    reader
+        .take(length as u64)
+        .read_to_end(&mut bytes)
+        .map_err(Error::Io)?;
+    if bytes.len() != length {
+        return Err(Error::format("truncated .npy data"));
+    }
+    let data = bytes
+        .chunks_exact(T::SIZE)
+        .map(|chunk| T::from_bytes(chunk, big_endian))
+        .collect();
+    Ok((header, data))
+}
+
+// a 1-D array is read as a column
+pub fn read_array<R: Read, T: Element>(mut reader: R) -> Result<Array2d<T>, Error> {
+    let (header, data) = read_data::<R, T>(&mut reader)?;
+    let (height, width) = match header.shape[..] {
+        [n] => (n, 1),
+        [height, width] => (height, width),
+        _ => {
+            return Err(Error::format(format!(
+                "expected a 2-D array, found shape {:?}",
+                header.shape
+            )))
+        }
+    };
+    // the data already had to be there, but an empty shape like (huge, 0) costs nothing to
+    // claim and a pass over its rows later
+    if data.is_empty() {
+        return Ok(Array2d::try_from(0, 0, data).expect("Empty data fits 0x0"));
+    }
+    Ok(match header.order {
+        Order::C => Array2d::try_from(height, width, data).expect("Shape matches data"),
+        Order::Fortran => Array2d::gen(height, width, |i, j| data[j * height + i]),
+    })
+}
+
+// accepts 1-D arrays as well as single rows and columns
+pub fn read_vec<R: Read, T: Element>(mut reader: R) -> Result<Vec<T>, Error> {
+    let (header, data) = read_data::<R, T>(&mut reader)?;
+    match header.shape[..] {
+        [_] | [1, _] | [_, 1] => Ok(data),
+        _ => Err(Error::format(format!(
+            "expected a vector, found shape {:?}",
+            header.shape
+        ))),
+    }
+}
+
+fn write_npy<W: Write, T: Element>(
+    mut writer: W,
+    shape: &[usize],
+    order: Order,
+    data: &[u8],
+) -> Result<(), Error> {
+    let shape = match shape {
+        [n] => format!("({},)", n),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(usize::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '<{}', 'fortran_order': {}, 'shape': {}, }}",
+        T::KIND,
+        if order == Order::Fortran {
+            "True"
+        } else {
+            "False"
+        },
+        shape
+    );
+    // the data starts on a 64 byte boundary
+    let padding = 63 - (MAGIC.len() + 4 + header.len()) % 64;
+    header.extend(std::iter::repeat_n(' ', padding));
+    header.push('\n');
+
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[1, 0])?;
+    let length = u16::try_from(header.len())
+        .map_err(|_| Error::format("shape too long for a version 1.0 header"))?;
+    writer.write_all(&length.to_le_bytes())?;
+    writer.write_all(header.as_bytes())?;
+    writer.write_all(data)?;
+    Ok(())
+}
+
+fn array_bytes<M: Matrix<Item = T>, T: Element>(m: &M, order: Order) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(m.height() * m.width() * T::SIZE);
+    let at = |i: usize, j: usize| *m.at(i, j).expect("Index within matrix dimensions");
+    match order {
+        Order::C => (0..m.height())
+            .flat_map(|i| (0..m.width()).map(move |j| (i, j)))
+            .for_each(|(i, j)| at(i, j).to_le_bytes(&mut bytes)),
+        Order::Fortran => (0..m.width())
+            .flat_map(|j| (0..m.height()).map(move |i| (i, j)))
+            .for_each(|(i, j)| at(i, j).to_le_bytes(&mut bytes)),
+    }
+    bytes
+}
+
+fn vec_bytes<T: Element>(v: &[T]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(v.len() * T::SIZE);
+    v.iter().for_each(|x| x.to_le_bytes(&mut bytes));
+    bytes
+}
+
+pub fn write_array<W: Write, M: Matrix<Item = T>, T: Element>(
+    writer: W,
+    m: &M,
+    order: Order,
+) -> Result<(), Error> {
+    write_npy::<W, T>(
+        writer,
+        &[m.height(), m.width()],
+        order,
+        &array_bytes(m, order),
+    )
+}
+
+pub fn write_vec<W: Write, T: Element>(writer: W, v: &[T]) -> Result<(), Error> {
+    write_npy::<W, T>(writer, &[v.len()], Order::C, &vec_bytes(v))
+}
+
+pub fn load_array<P: AsRef<Path>, T: Element>(path: P) -> Result<Array2d<T>, Error> {
+    read_array(std::io::BufReader::new(std::fs::File::open(path)?))
+}
+
+pub fn load_vec<P: AsRef<Path>, T: Element>(path: P) -> Result<Vec<T>, Error> {
+    read_vec(std::io::BufReader::new(std::fs::File::open(path)?))
+}
+
+pub fn save_array<P: AsRef<Path>, M: Matrix<Item = T>, T: Element>(
+    path: P,
+    m: &M,
+    order: Order,
+) -> Result<(), Error> {
+    write_array(std::fs::File::create(path)?, m, order)
+}
+
+pub fn save_vec<P: AsRef<Path>, T: Element>(path: P, v: &[T]) -> Result<(), Error> {
+    write_vec(std::fs::File::create(path)?, v)
+}
+
+fn u16_at(bytes: &[u8], at: usize) -> Result<u16, Error> {
+    bytes
+        .get(at..at + 2)
+        .map(|b| u16::from_le_bytes(b.try_into().expect("Two bytes")))
+        .ok_or_else(|| Error::format("truncated .npz archive"))
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> Result<u32, Error> {
+    bytes
+        .get(at..at + 4)
+        .map(|b| u32::from_le_bytes(b.try_into().expect("Four bytes")))
+        .ok_or_else(|| Error::format("truncated .npz archive"))
+}
+
+fn u64_at(bytes: &[u8], at: usize) -> Result<u64, Error> {
+    bytes
+        .get(at..at + 8)
+        .map(|b| u64::from_le_bytes(b.try_into().expect("Eight bytes")))
+        .ok_or_else(|| Error::format("truncated .npz archive"))
+}
+
+// an uncompressed zip archive of .npy files, as written by `numpy.savez`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Npz {
+    entries: Vec<(String, Vec<u8>)>,
+}
+
+impl Npz {
+    pub fn new() -> Npz {
+        Npz::default()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(name, _)| name.as_str())
+    }
+
+    fn entry(&self, name: &str) -> Result<&[u8], Error> {
+        self.entries
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, bytes)| bytes.as_slice())
+            .ok_or_else(|| Error::format(format!("no array named `{}` in the archive", name)))
+    }
+
+    pub fn array<T: Element>(&self, name: &str) -> Result<Array2d<T>, Error> {
+        read_array(self.entry(name)?)
+    }
+
+    pub fn vec<T: Element>(&self, name: &str) -> Result<Vec<T>, Error> {
+        read_vec(self.entry(name)?)
+    }
+
+    fn insert(&mut self, name: &str, bytes: Vec<u8>) {
+        self.entries.retain(|(n, _)| n != name);
+        self.entries.push((name.to_string(), bytes));
+    }
+
+    pub fn insert_array<M: Matrix<Item = T>, T: Element>(
+        &mut self,
+        name: &str,
+        m: &M,
+        order: Order,
+    ) -> Result<(), Error> {
+        let mut bytes = vec![];
+        write_array(&mut bytes, m, order)?;
+        self.insert(name, bytes);
+        Ok(())
+    }
+
+    pub fn insert_vec<T: Element>(&mut self, name: &str, v: &[T]) -> Result<(), Error> {
+        let mut bytes = vec![];
+        write_vec(&mut bytes, v)?;
+        self.insert(name, bytes);
+        Ok(())
+    }
+
+    pub fn read<R: Read>(mut reader: R) -> Result<Npz, Error> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+
+        // the end of central directory record sits within the last 64 KiB
+        let end = (0..=bytes.len().saturating_sub(22))
+            .rev()
+            .take(65536 + 22)
+            .find(|&at| u32_at(&bytes, at).ok() == Some(0x0605_4b50))
+            .ok_or_else(|| Error::format("not a zip archive"))?;
+        let count = u16_at(&bytes, end + 10)? as usize;
+        let mut at = u32_at(&bytes, end + 16)? as usize;
+
+        let mut npz = Npz::new();
+        for _ in 0..count {
+            if u32_at(&bytes, at)? != 0x0201_4b50 {
+                return Err(Error::format("corrupted zip central directory"));
+            }
+            let method = u16_at(&bytes, at + 10)?;
+            let crc = u32_at(&bytes, at + 16)?;
+            let mut size = u32_at(&bytes, at + 24)? as u64;
+            let name_length = u16_at(&bytes, at + 28)? as usize;
+            let extra_length = u16_at(&bytes, at + 30)? as usize;
+            let comment_length = u16_at(&bytes, at + 32)? as usize;
+            let mut offset = u32_at(&bytes, at + 42)? as u64;
+            let name = bytes
+                .get(at + 46..at + 46 + name_length)
+                .ok_or_else(|| Error::format("truncated .npz archive"))?;
+            let name = String::from_utf8_lossy(name).to_string();
+
+            // zip64 fields are present only for the values saturated above
+            let mut extra = at + 46 + name_length;
+            let extra_end = extra + extra_length;
+            while extra + 4 <= extra_end {
+                let id = u16_at(&bytes, extra)?;
+                let length = u16_at(&bytes, extra + 2)? as usize;
+                if id == 0x0001 {
+                    let mut field = extra + 4;
+                    if size == 0xFFFF_FFFF {
+                        size = u64_at(&bytes, field)?;
+                        field += 8;
+                    }
+                    if u32_at(&bytes, at + 20)? == 0xFFFF_FFFF {
+                        field += 8;
+                    }
+                    if offset == 0xFFFF_FFFF {
+                        offset = u64_at(&bytes, field)?;
+                    }
+                }
+                extra += 4 + length;
+            }
+            at = extra_end + comment_length;
+
+            if method != 0 {
+                return Err(Error::format(format!(
+                    "`{}` is compressed, only uncompressed .npz archives are supported",
+                    name
+                )));
+            }
+            let local = offset as usize;
+            if u32_at(&bytes, local)? != 0x0403_4b50 {
+                return Err(Error::format(format!("corrupted zip entry `{}`", name)));
+            }
+            let start = local
+                + 30
+                + u16_at(&bytes, local + 26)? as usize
+                + u16_at(&bytes, local + 28)? as usize;
+            let data = bytes
+                .get(start..start + size as usize)
+                .ok_or_else(|| Error::format(format!("truncated zip entry `{}`", name)))?;
+            if crc32(data) != crc {
+                return Err(Error::format(format!("checksum mismatch in `{}`", name)));
+            }
+            let name = name.strip_suffix(".npy").unwrap_or(&name).to_string();
+            npz.entries.push((name, data.to_vec()));
+        }
+        Ok(npz)
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        let too_large = || Error::format(".npz archives over 4 GiB are not supported");
+        // DOS date 1980-01-01, midnight
+        let (time, date) = (0u16, 0x21u16);
+        let mut offset = 0usize;
+        let mut directory = vec![];
+        for (name, data) in &self.entries {
+            let name = format!("{}.npy", name);
+            let crc = crc32(data);
+            let size = u32::try_from(data.len()).map_err(|_| too_large())?;
+
+            let mut local = vec![];
+            local.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+            for field in [20u16, 0, 0, time, date] {
+                local.extend_from_slice(&field.to_le_bytes());
+            }
+            for field in [crc, size, size] {
+                local.extend_from_slice(&field.to_le_bytes());
+            }
+            local.extend_from_slice(&(name.len() as u16).to_le_bytes());
+            local.extend_from_slice(&0u16.to_le_bytes());
+            local.extend_from_slice(name.as_bytes());
+
+            directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+            for field in [20u16, 20, 0, 0, time, date] {
+                directory.extend_from_slice(&field.to_le_bytes());
+            }
+            for field in [crc, size, size] {
+                directory.extend_from_slice(&field.to_le_bytes());
+            }
+            for field in [name.len() as u16, 0, 0, 0, 0] {
+                directory.extend_from_slice(&field.to_le_bytes());
+            }
+            directory.extend_from_slice(&0u32.to_le_bytes());
+            let local_offset = u32::try_from(offset).map_err(|_| too_large())?;
+            directory.extend_from_slice(&local_offset.to_le_bytes());
+            directory.extend_from_slice(name.as_bytes());
+
+            writer.write_all(&local)?;
+            writer.write_all(data)?;
+            offset += local.len() + data.len();
+        }
+
+        let count = u16::try_from(self.entries.len())
+            .map_err(|_| Error::format("too many arrays for one .npz archive"))?;
+        let mut end = vec![];
+        end.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        for field in [0u16, 0, count, count] {
+            end.extend_from_slice(&field.to_le_bytes());
+        }
+        end.extend_from_slice(&(directory.len() as u32).to_le_bytes());
+        end.extend_from_slice(
+            &u32::try_from(offset)
+                .map_err(|_| too_large())?
+                .to_le_bytes(),
+        );
+        end.extend_from_slice(&0u16.to_le_bytes());
+
+        writer.write_all(&directory)?;
+        writer.write_all(&end)?;
+        Ok(())
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Npz, Error> {
+        Npz::read(std::fs::File::open(path)?)
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        self.write(std::io::BufWriter::new(std::fs::File::create(path)?))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::io::npy::{self, Npz, Order};
+    use crate::io::Error;
+    use crate::{Array2d, Matrix};
+
+    // numpy.save(f, numpy.arange(6.0).reshape(2, 3))
+    fn numpy_bytes(descr: &str, fortran: bool, data: &[u8]) -> Vec<u8> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': (2, 3), }}",
+            descr,
+            if fortran { "True" } else { "False" }
+        );
+        while (10 + header.len() + 1) % 64 != 0 {
+            header.push(' ');
+        }
+        header.push('\n');
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    #[test]
+    fn reads_numpy_layout() {
+        let expected = Array2d::gen(2, 3, |i, j| (i * 3 + j) as f64);
+
+        let data: Vec<u8> = (0..6).flat_map(|x| (x as f64).to_le_bytes()).collect();
+        let bytes = numpy_bytes("<f8", false, &data);
+        assert_eq!(
+            npy::read_array::<_, f64>(bytes.as_slice()).unwrap(),
+            expected
+        );
+
+        let data: Vec<u8> = [0, 3, 1, 4, 2, 5]
+            .iter()
+            .flat_map(|&x| (x as f32).to_be_bytes())
+            .collect();
+        let bytes = numpy_bytes(">f4", true, &data);
+        let a = npy::read_array::<_, f32>(bytes.as_slice()).unwrap();
+        assert_eq!(a, expected.map(|&x| x as f32));
+
+        let mut written = vec![];
+        npy::write_array(&mut written, &expected, Order::C).unwrap();
+        let data: Vec<u8> = (0..6).flat_map(|x| (x as f64).to_le_bytes()).collect();
+        assert_eq!(written, numpy_bytes("<f8", false, &data));
+    }
+
+    #[test]
+    fn round_trip_both_orders() {
+        let mut a = Array2d::gen(3, 4, |i, j| (i as f64 - j as f64) / 3.0);
+        a.swap_columns(0, 3);
+        for order in [Order::C, Order::Fortran] {
+            let mut bytes = vec![];
+            npy::write_array(&mut bytes, &a, order).unwrap();
+            assert_eq!(bytes.len() % 64, (12 * 8) % 64);
+            assert_eq!(npy::read_array::<_, f64>(bytes.as_slice()).unwrap(), a);
+        }
+
+        let x = vec![1.0, -2.5, f64::INFINITY];
+        let mut bytes = vec![];
+        npy::write_vec(&mut bytes, &x).unwrap();
+        assert!(String::from_utf8_lossy(&bytes).contains("'shape': (3,)"));
+        assert_eq!(npy::read_vec::<_, f64>(bytes.as_slice()).unwrap(), x);
+        let column = npy::read_array::<_, f64>(bytes.as_slice()).unwrap();
+        assert_eq!((column.height(), column.width()), (3, 1));
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let data: Vec<u8> = (0..6).flat_map(|x| (x as f64).to_le_bytes()).collect();
+        let bytes = numpy_bytes("<f8", false, &data);
+        assert!(matches!(
+            npy::read_array::<_, f32>(bytes.as_slice()),
+            Err(Error::Format(_))
+        ));
+        assert!(matches!(
+            npy::read_vec::<_, f64>(bytes.as_slice()),
+            Err(Error::Format(_))
+        ));
+        match npy::read_array::<_, f64>(&bytes[..bytes.len() - 1]) {
+            Err(e) => assert_eq!(e.to_string(), "truncated .npy data"),
+            Ok(_) => unreachable!(),
+        }
+        assert!(npy::read_array::<_, f64>(&b"PK\x03\x04"[..]).is_err());
+        let bytes = numpy_bytes("<f8", false, &data);
+        let broken: Vec<u8> = String::from_utf8_lossy(&bytes)
+            .replace("'shape': (2, 3)", "'shape': [2, 3]")
+            .into_bytes();
+        assert!(npy::read_array::<_, f64>(&broken[..]).is_err());
+
+        // the header keeps its length, the new shape eats into the padding
+        let resized_from = |bytes: &[u8], shape: &str| {
+            let to = format!("'shape': {}, }}", shape);
+            let from = format!("'shape': (2, 3), }}{}", " ".repeat(to.len() - 18));
+            let start = bytes
+                .windows(from.len())
+                .position(|w| w == from.as_bytes())
+                .unwrap();
+            let mut resized = bytes.to_vec();
+            resized[start..start + to.len()].copy_from_slice(to.as_bytes());
+            resized
+        };
+        let resized = |shape: &str| resized_from(&bytes, shape);
+        match npy::read_array::<_, f64>(&resized("(4611686018427387904, 4)")[..]) {
+            Err(e) => assert_eq!(e.to_string(), "shape [4611686018427387904, 4] is too large"),
+            Ok(_) => unreachable!(),
+        }
+        match npy::read_array::<_, f64>(&resized("(1000000000000, 1000)")[..]) {
+            Err(e) => assert_eq!(e.to_string(), "truncated .npy data"),
+            Ok(_) => unreachable!(),
+        }
+
+        // nothing to read, so nothing is built row by row either
+        let fortran = numpy_bytes("<f8", true, &[]);
+        let empty = resized_from(&fortran, "(18446744073709551615, 0)");
+        let a = npy::read_array::<_, f64>(&empty[..]).unwrap();
+        assert_eq!((a.height(), a.width()), (0, 0));
+    }
+
+    #[test]
+    fn npz_round_trip() {
+        let a = Array2d::gen(4, 4, |i, j| 1.0 / (i + j + 1) as f64);
+        let x = vec![1.0, 2.0, 3.0, 4.0];
+        let mut npz = Npz::new();
+        npz.insert_array("a", &a, Order::Fortran).unwrap();
+        npz.insert_array("a32", &a.map(|&v| v as f32), Order::C)
+            .unwrap();
+        npz.insert_vec("x", &x).unwrap();
+
+        let mut bytes = vec![];
+        npz.write(&mut bytes).unwrap();
+        let read = Npz::read(bytes.as_slice()).unwrap();
+        assert_eq!(read, npz);
+        assert_eq!(read.names().collect::<Vec<_>>(), vec!["a", "a32", "x"]);
+        assert_eq!(read.array::<f64>("a").unwrap(), a);
+        assert_eq!(read.vec::<f64>("x").unwrap(), x);
+        assert_eq!(read.array::<f32>("a32").unwrap(), a.map(|&v| v as f32));
+        assert!(read.array::<f64>("b").is_err());
+
+        // flip a byte inside the first entry's data
+        bytes[100] ^= 0xFF;
+        match Npz::read(bytes.as_slice()) {
+            Err(e) => assert_eq!(e.to_string(), "checksum mismatch in `a.npy`"),
+            Ok(_) => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,404 @@
+use crate::io::Error;
+use crate::{Array2d, Matrix, Meow};
+use std::io::{BufRead, BufWriter, Write};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dialect {
+    // None splits on any run of whitespace
+    pub delimiter: Option<char>,
+    // the first data line holds column names
+    pub header: bool,
+    // everything from this character to the end of a line is ignored
+    pub comment: Option<char>,
+}
+
+impl Dialect {
+    pub fn csv() -> Dialect {
+        Dialect {
+            delimiter: Some(','),
+            header: false,
+            comment: Some('#'),
+        }
+    }
+
+    pub fn whitespace() -> Dialect {
+        Dialect {
+            delimiter: None,
+            ..Dialect::csv()
+        }
+    }
+
+    fn separates(&self, c: char) -> bool {
+        match self.delimiter {
+            Some(delimiter) => c == delimiter,
+            None => c.is_whitespace(),
+        }
+    }
+
+    // a quoted field keeps delimiters and comment characters, `""` in it is one quote
+    fn split(&self, line: &str) -> Vec<String> {
+        let mut fields = vec![];
+        let mut field = String::new();
+        // unquoted whitespace, dropped at the ends of a field
+        let mut gap = String::new();
+        let (mut quoted, mut present) = (false, false);
+        let mut chars = line.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '"' {
+                if quoted && chars.peek() == Some(&'"') {
+                    chars.next();
+                } else {
+                    quoted = !quoted;
+                    present = true;
+                    continue;
+                }
+            } else if !quoted && self.separates(c) {
+                // a run of whitespace is a single separator
+                if present || self.delimiter.is_some() {
+                    fields.push(std::mem::take(&mut field));
+                }
+                gap.clear();
+                present = false;
+                continue;
+            } else if !quoted && c.is_whitespace() {
+                gap.push(c);
+                continue;
+            }
+            if !field.is_empty() {
+                field.push_str(&gap);
+            }
+            gap.clear();
+            field.push(c);
+            present = true;
+        }
+        if present || self.delimiter.is_some() {
+            fields.push(field);
+        }
+        fields
+    }
+
+    fn comment_start(&self, line: &str) -> Option<usize> {
+        let comment = self.comment?;
+        let mut quoted = false;
+        line.char_indices()
+            .find(|&(_, c)| {
+                if c == '"' {
+                    quoted = !quoted;
+                }
+                c == comment && !quoted
+            })
+            .map(|(start, _)| start)
+    }
+
+    // the inverse of `split`
+    pub fn quote(&self, field: &str) -> String {
+        let special =
+            |c: char| c == '"' || c == '\n' || self.separates(c) || Some(c) == self.comment;
+        // unquoted, an empty field vanishes between whitespace and the ends are trimmed
+        let trimmed = field.trim() != field || (field.is_empty() && self.delimiter.is_none());
+        if field.contains(special) || trimmed {
+            format!("\"{}\"", field.replace('"', "\"\""))
+        } else {
+            field.to_string()
+        }
+    }
+
+    fn separator(&self) -> String {
+        match self.delimiter {
+            Some(delimiter) => delimiter.to_string(),
+            None => " ".to_string(),
+        }
+    }
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Dialect::csv()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub header: Option<Vec<String>>,
+    pub data: Array2d<f64>,
+    // line of the first data row, used to point at shape errors
+    pub first_line: usize,
+}
+
+// for CSV written elsewhere, such as the sweep records
+pub fn csv_field(text: &str) -> String {
+    Dialect::csv().quote(text)
+}
+
+pub fn read<R: BufRead>(reader: R, dialect: &Dialect) -> Result<Table, Error> {
+    let mut header = None;
+    let mut first_line = 0;
+    let mut width = None;
+    let mut data = vec![];
+    for (index, text) in reader.lines().enumerate() {
+        let line = index + 1;
+        let text = text?;
+        let text = match dialect.comment_start(&text) {
+            Some(start) => &text[..start],
+            None => &text[..],
+        };
+        if text.trim().is_empty() {
+            continue;
+        }
+        let fields = dialect.split(text);
+
+        if dialect.header && header.is_none() {
+            width = Some(fields.len());
+            header = Some(fields);
+            continue;
+        }
+        match width {
+            Some(width) if width != fields.len() => {
+                return Err(Error::parse(
+                    line,
+                    format!("expected {} columns, found {}", width, fields.len()),
+                ))
+            }
+            _ => width = Some(fields.len()),
+        }
+        if first_line == 0 {
+            first_line = line;
+        }
+        for (column, field) in fields.iter().enumerate() {
+            let value = field.parse().map_err(|_| {
+                Error::parse(
+                    line,
+                    format!("invalid number `{}` in column {}", field, column + 1),
+                )
+            })?;
+            data.push(value);
+        }
+    }
+
+    let width = width.unwrap_or(0);
+    let height = data.len().checked_div(width).unwrap_or(0);
+    Ok(Table {
+        header,
+        data: Array2d::try_from(height, width, data).expect("Every row has `width` values"),
+        first_line,
+    })
+}
+
+pub fn write<W: Write, M: Matrix<Item = f64>>(
+    writer: W,
+    m: &M,
+    dialect: &Dialect,
+    header: Option<&[&str]>,
+) -> Result<(), Error> {
+    let mut writer = BufWriter::new(writer);
+    let separator = dialect.separator();
+    if let Some(header) = header {
+        if header.len() != m.width() {
+            return Err(Error::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("{} names for {} columns", header.len(), m.width()),
+            )));
+        }
+        let names: Vec<String> = header.iter().map(|name| dialect.quote(name)).collect();
+        writeln!(writer, "{}", names.join(&separator))?;
+    }
+    for i in 0..m.height() {
+        for j in 0..m.width() {
+            if j > 0 {
+                write!(writer, "{}", separator)?;
+            }
+            write!(
+                writer,
+                "{}",
+                m.at(i, j).expect("Index within matrix dimensions")
+            )?;
+        }
+        writeln!(writer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// [A | b] with b taken from the last column of an n x (n + 1) matrix
+pub fn split_rhs(m: &Array2d<f64>) -> Option<Meow<Array2d<f64>>> {
+    let n = m.height();
+    if m.width() != n + 1 {
+        return None;
+    }
+    let mut system = Meow::from(Array2d::gen(n, n, |i, j| *m.at(i, j).unwrap()));
+    system
+        .eat(Array2d::gen(n, 1, |i, _| *m.at(i, n).unwrap()))
+        .ok()?;
+    Some(system)
+}
+
+pub fn read_system<R: BufRead>(reader: R, dialect: &Dialect) -> Result<Meow<Array2d<f64>>, Error> {
+    let table = read(reader, dialect)?;
+    split_rhs(&table.data).ok_or_else(|| {
+        Error::parse(
+            table.first_line.max(1),
+            format!(
+                "expected {} columns for a system with {} equations, found {}",
+                table.data.height() + 1,
+                table.data.height(),
+                table.data.width()
+            ),
+        )
+    })
+}
+
+impl Array2d<f64> {
+    pub fn read_csv<R: BufRead>(reader: R, dialect: &Dialect) -> Result<Array2d<f64>, Error> {
+        read(reader, dialect).map(|table| table.data)
+    }
+
+    pub fn write_csv<W: Write>(&self, writer: W, dialect: &Dialect) -> Result<(), Error> {
+        let names: Vec<String> = (1..=self.width()).map(|j| format!("c{}", j)).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        write(writer, self, dialect, dialect.header.then_some(&names[..]))
+    }
+
+    pub fn read_text<R: BufRead>(reader: R) -> Result<Array2d<f64>, Error> {
+        Array2d::read_csv(reader, &Dialect::whitespace())
+    }
+
+    pub fn write_text<W: Write>(&self, writer: W) -> Result<(), Error> {
+        self.write_csv(writer, &Dialect::whitespace())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::io::text::{self, Dialect};
+    use crate::io::Error;
+    use crate::{Array2d, Matrix};
+
+    #[test]
+    fn csv_with_header_and_comments() {
+        let text = "# exported from a spreadsheet
+\"x\",\"y\", z
+1, 2.5, -3e-1
+
+4,5,6 # trailing comment
+";
+        let dialect = Dialect {
+            header: true,
+            ..Dialect::csv()
+        };
+        let table = text::read(text.as_bytes(), &dialect).unwrap();
+        assert_eq!(
+            table.header,
+            Some(vec!["x".to_string(), "y".to_string(), "z".to_string()])
+        );
+        assert_eq!(table.first_line, 3);
+        assert_eq!(
+            table.data,
+            Array2d::try_from(2, 3, vec![1.0, 2.5, -0.3, 4.0, 5.0, 6.0]).unwrap()
+        );
+    }
+
+    #[test]
+    fn errors_carry_line_numbers() {
+        let text = "1;2\n3;4\n5\n";
+        let dialect = Dialect {
+            delimiter: Some(';'),
+            ..Dialect::csv()
+        };
+        match Array2d::read_csv(text.as_bytes(), &dialect) {
+            Err(Error::Parse { line, message }) => {
+                assert_eq!(line, 3);
+                assert_eq!(message, "expected 2 columns, found 1");
+            }
+            other => panic!("{:?}", other),
+        }
+        match Array2d::read_text("1 2\n3 four\n".as_bytes()) {
+            Err(e) => assert_eq!(e.to_string(), "line 2: invalid number `four` in column 2"),
+            Ok(_) => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut a = Array2d::try_from(2, 3, vec![1.0, -2.5, 1e-20, 0.1, 3.0, 7.0]).unwrap();
+        a.swap_rows(0, 1);
+        for dialect in [
+            Dialect::whitespace(),
+            Dialect {
+                delimiter: Some('\t'),
+                header: true,
+                comment: None,
+            },
+        ] {
+            let mut buffer = vec![];
+            a.write_csv(&mut buffer, &dialect).unwrap();
+            assert_eq!(Array2d::read_csv(buffer.as_slice(), &dialect).unwrap(), a);
+        }
+        let mut buffer = vec![];
+        a.write_text(&mut buffer).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "0.1 3 7\n1 -2.5 0.00000000000000000001\n"
+        );
+        assert_eq!(Array2d::read_text("".as_bytes()).unwrap().height(), 0);
+    }
+
+    #[test]
+    fn quoted_names_round_trip() {
+        let a = Array2d::try_from(1, 4, vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+        let names = ["a,b", "say \"hi\"", "# not a comment", " padded "];
+        for dialect in [
+            Dialect {
+                header: true,
+                ..Dialect::csv()
+            },
+            Dialect {
+                header: true,
+                ..Dialect::whitespace()
+            },
+        ] {
+            let mut buffer = vec![];
+            text::write(&mut buffer, &a, &dialect, Some(&names)).unwrap();
+            let table = text::read(buffer.as_slice(), &dialect).unwrap();
+            assert_eq!(table.header.unwrap(), names, "{:?}", dialect);
+            assert_eq!(table.data, a);
+        }
+
+        let mut buffer = vec![];
+        text::write(&mut buffer, &a, &Dialect::csv(), Some(&names)).unwrap();
+        assert_eq!(
+            String::from_utf8(buffer).unwrap(),
+            "\"a,b\",\"say \"\"hi\"\"\",\"# not a comment\",\" padded \"\n1,2,3,4\n"
+        );
+    }
+
+    #[test]
+    fn augmented_system() {
+        let text = "2 1 3\n1 3 5\n";
+        let system = text::read_system(text.as_bytes(), &Dialect::whitespace()).unwrap();
+        assert_eq!(system.width(), 3);
+        assert_eq!(
+            system.calculate(0).unwrap(),
+            Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 3.0]).unwrap()
+        );
+        assert_eq!(
+            system.calculate(1).unwrap(),
+            Array2d::try_from(2, 1, vec![3.0, 5.0]).unwrap()
+        );
+
+        let mut buffer = vec![];
+        text::write(
+            &mut buffer,
+            &system,
+            &Dialect::csv(),
+            Some(&["x", "y", "b"]),
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "x,y,b\n2,1,3\n1,3,5\n");
+
+        match text::read_system("# header\n1 2\n3 4\n".as_bytes(), &Dialect::whitespace()) {
+            Err(Error::Parse { line, .. }) => assert_eq!(line, 2),
+            Err(e) => panic!("{}", e),
+            Ok(_) => panic!("square matrix has no right-hand side"),
+        }
+    }
+}
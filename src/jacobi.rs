@@ -1,3 +1,4 @@
+use crate::properties::{self, Dominance};
 use crate::{Iteratable, Matrix};
 
 pub fn converges_for<M: Matrix<Item = f64>>(m: &M) -> bool {
@@ -11,6 +12,150 @@ pub fn converges_for<M: Matrix<Item = f64>>(m: &M) -> bool {
     true
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Convergence {
+    pub row_dominance: Dominance,
+    pub column_dominance: Dominance,
+    pub irreducibly_dominant: bool,
+    pub positive_definite: bool,
+    pub jacobi_radius: f64,
+    pub gauss_seidel_radius: f64,
+}
+
+impl Convergence {
+    fn sufficient(&self) -> bool {
+        self.row_dominance == Dominance::Strict
+            || self.column_dominance == Dominance::Strict
+            || self.irreducibly_dominant
+    }
+
+    pub fn jacobi_converges(&self) -> bool {
+        self.sufficient() || self.jacobi_radius < 1.0
+    }
+
+    // this is the method `iterate_on` implements
+    pub fn gauss_seidel_converges(&self) -> bool {
+        self.sufficient() || self.positive_definite || self.gauss_seidel_radius < 1.0
+    }
+
+    pub fn jacobi_iterations(&self, epsilon: f64) -> Option<usize> {
+        predict(self.jacobi_radius, epsilon)
+    }
+
+    pub fn gauss_seidel_iterations(&self, epsilon: f64) -> Option<usize> {
+        predict(self.gauss_seidel_radius, epsilon)
+    }
+}
+
+// number of steps for the error to shrink by `epsilon` at the asymptotic rate
+fn predict(radius: f64, epsilon: f64) -> Option<usize> {
+    if radius >= 1.0 || !radius.is_finite() {
+        None
+    } else if radius == 0.0 {
+        Some(1)
+    } else {
+        Some((epsilon.ln() / radius.ln()).ceil().max(1.0) as usize)
+    }
+}
+
+fn reachable<F: Fn(usize, usize) -> bool>(n: usize, edge: F) -> bool {
+    let mut seen = vec![false; n];
+    let mut stack = vec![0];
+    seen[0] = true;
+    while let Some(i) = stack.pop() {
+        for (j, seen) in seen.iter_mut().enumerate() {
+            if !*seen && edge(i, j) {
+                *seen = true;
+                stack.push(j);
+            }
+        }
+    }
+    seen.into_iter().all(|seen| seen)
+}
+
+pub fn irreducible<M: Matrix<Item = f64>>(m: &M) -> bool {
+    let n = m.height();
+    let edge = |i: usize, j: usize| i != j && *m.at(i, j).unwrap_or(&0.0) != 0.0;
+    n <= 1 || (reachable(n, edge) && reachable(n, |i, j| edge(j, i)))
+}
+
+pub const POWER_ITERATIONS: usize = 300;
+
+// spectral radius of a linear map estimated as the geometric mean growth of ‖Tᵏx‖,
+// which also copes with complex conjugate dominant eigenvalues
+fn radius<F: Fn(&[f64]) -> Vec<f64>>(n: usize, apply: F) -> f64 {
+    let mut x: Vec<f64> = (0..n).map(|i| 1.0 + (i % 7) as f64 / 7.0).collect();
+    let warmup = POWER_ITERATIONS / 3;
+    let mut log = 0.0;
+    for step in 0..POWER_ITERATIONS {
+        let y = apply(&x);
+        let norm = y.iter().cloned().map(f64::abs).fold(0.0, f64::max);
+        if norm == 0.0 {
+            return 0.0;
+        }
+        if !norm.is_finite() {
+            return f64::INFINITY;
+        }
+        if step >= warmup {
+            log += norm.ln();
+        }
+        x = y.into_iter().map(|y| y / norm).collect();
+    }
+    (log / (POWER_ITERATIONS - warmup) as f64).exp()
+}
+
+pub fn convergence<M: Matrix<Item = f64>>(m: &M) -> Option<Convergence> {
+    let n = m.height();
+    if n != m.width() || n == 0 {
+        return None;
+    }
+    let p = properties::analyze(m);
+    if !p.zero_diagonals.is_empty() {
+        return None;
+    }
+    let at = |i: usize, j: usize| *m.at(i, j).expect("Index within matrix dimensions");
+
+    let irreducibly_dominant = p.row_dominance == Dominance::Weak
+        && (0..n).any(|i| 2.0 * at(i, i).abs() > m.row(i).cloned().map(f64::abs).sum())
+        && irreducible(m);
+
+    // x -> -D⁻¹ (L + U) x
+    let jacobi_radius = radius(n, |x| {
+        (0..n)
+            .map(|i| {
+                let sum: f64 = (0..n).filter(|&j| j != i).map(|j| at(i, j) * x[j]).sum();
+                -sum / at(i, i)
+            })
+            .collect()
+    });
+
+    // x -> -(D + L)⁻¹ U x
+    let gauss_seidel_radius = radius(n, |x| {
+        let mut y = vec![0.0; n];
+        for i in 0..n {
+            let mut sum = 0.0;
+            for (j, y) in y.iter().enumerate().take(i) {
+                sum += at(i, j) * y;
+            }
+            for (j, x) in x.iter().enumerate().skip(i + 1) {
+                sum += at(i, j) * x;
+            }
+            y[i] = -sum / at(i, i);
+        }
+        y
+    });
+
+    Some(Convergence {
+        row_dominance: p.row_dominance,
+        column_dominance: p.column_dominance,
+        irreducibly_dominant,
+        positive_definite: p.positive_definite,
+        jacobi_radius,
+        gauss_seidel_radius,
+    })
+}
+
 pub fn dx(a: &Vec<f64>, b: &Vec<f64>) -> f64 {
     std::iter::zip(a, b)
         .map(|(a, b)| (a - b).abs())
@@ -18,35 +163,165 @@ pub fn dx(a: &Vec<f64>, b: &Vec<f64>) -> f64 {
         .expect("There wasn't a single element")
 }
 
-pub fn iterate_on<M: Matrix<Item = f64>>(x: &mut [f64], epsilon: f64, m: &M, b: &[f64]) -> usize {
-    let mut iterations = 0;
-    let mut dx = vec![0.0; x.len()];
-    loop {
-        for i in 0..x.len() {
-            let diag = *m.at(i, i).expect("The diagonal element was not present");
-            dx[i] = b[i] / diag;
-            for (j, &value) in x.iter().enumerate() {
-                if j == i {
-                    continue;
-                }
-                dx[i] -= m.at(i, j).unwrap() / diag * value;
+// one Gauss-Seidel sweep, returns the largest change of x
+fn sweep<M: Matrix<Item = f64>>(x: &mut [f64], m: &M, b: &[f64]) -> f64 {
+    let mut largest: f64 = 0.0;
+    for i in 0..x.len() {
+        let diag = *m.at(i, i).expect("The diagonal element was not present");
+        let mut next = b[i] / diag;
+        for (j, &value) in x.iter().enumerate() {
+            if j == i {
+                continue;
             }
-            x[i] += dx[i];
+            next -= m.at(i, j).unwrap() / diag * value;
         }
+        let dx = (next - x[i]).abs();
+        // NaN has to survive, f64::max would drop it
+        largest = if dx.is_nan() { dx } else { largest.max(dx) };
+        x[i] = next;
+    }
+    largest
+}
 
+pub fn iterate_on<M: Matrix<Item = f64>>(x: &mut [f64], epsilon: f64, m: &M, b: &[f64]) -> usize {
+    let mut iterations = 0;
+    loop {
+        let dx = sweep(x, m, b);
         iterations += 1;
 
-        if dx
-            .iter()
-            .cloned()
-            .map(f64::abs)
-            .max_by(f64::total_cmp)
-            .expect("There wasn't a single elemnt")
-            < epsilon
-        {
+        if dx < epsilon {
             break;
         }
     }
 
     iterations
 }
+
+// Ok with the number of sweeps once converged, Err with it when x stopped being
+// finite or `max_iterations` ran out
+pub fn iterate_at_most<M: Matrix<Item = f64>>(
+    x: &mut [f64],
+    epsilon: f64,
+    m: &M,
+    b: &[f64],
+    max_iterations: usize,
+) -> Result<usize, usize> {
+    for iterations in 1..=max_iterations {
+        let dx = sweep(x, m, b);
+        if dx < epsilon {
+            return Ok(iterations);
+        }
+        if !dx.is_finite() {
+            return Err(iterations);
+        }
+    }
+    Err(max_iterations)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{diagnostics, jacobi, Array2d, Iteratable, Matrix};
+
+    #[test]
+    fn radius_of_diagonal_scaling() {
+        // Jacobi matrix is [[0, -1/2], [-1/2, 0]], Gauss-Seidel has eigenvalues 0 and 1/4
+        let a = Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 2.0]).unwrap();
+        let c = jacobi::convergence(&a).unwrap();
+        assert!((c.jacobi_radius - 0.5).abs() < 1e-9, "{:?}", c);
+        assert!((c.gauss_seidel_radius - 0.25).abs() < 1e-9, "{:?}", c);
+        assert!(c.jacobi_converges());
+        assert!(c.gauss_seidel_converges());
+    }
+
+    #[test]
+    fn weak_row_test_is_not_enough() {
+        let a = Array2d::try_from(2, 2, vec![1.0, 1.0, 1.0, 1.0]).unwrap();
+        assert!(jacobi::converges_for(&a));
+        let c = jacobi::convergence(&a).unwrap();
+        assert!(!c.irreducibly_dominant);
+        assert!(!c.gauss_seidel_converges());
+        assert_eq!(c.gauss_seidel_iterations(1e-6), None);
+    }
+
+    #[test]
+    fn irreducible_weak_dominance() {
+        let n = 6;
+        let a = Array2d::gen(n, n, |i, j| match i.abs_diff(j) {
+            0 => 2.0,
+            1 => -1.0,
+            _ => 0.0,
+        });
+        assert!(jacobi::irreducible(&a));
+        let c = jacobi::convergence(&a).unwrap();
+        assert!(c.irreducibly_dominant);
+        assert!(c.positive_definite);
+        let expected = (std::f64::consts::PI / (n + 1) as f64).cos();
+        assert!((c.jacobi_radius - expected).abs() < 1e-6, "{:?}", c);
+        assert!((c.gauss_seidel_radius - expected * expected).abs() < 1e-6);
+
+        let reducible = Array2d::gen(n, n, |i, j| if i == j { 1.0 } else { 0.0 });
+        assert!(!jacobi::irreducible(&reducible));
+    }
+
+    #[test]
+    fn positive_definite_without_dominance() {
+        let a = Array2d::try_from(3, 3, vec![1.0, 0.9, 0.9, 0.9, 1.0, 0.9, 0.9, 0.9, 1.0]).unwrap();
+        assert!(!jacobi::converges_for(&a));
+        let c = jacobi::convergence(&a).unwrap();
+        assert!(c.gauss_seidel_converges());
+        assert!(!c.jacobi_converges());
+    }
+
+    #[test]
+    fn prediction_matches_iterations() {
+        let n = 8;
+        let a = Array2d::gen(n, n, |i, j| {
+            if i == j {
+                4.0
+            } else {
+                1.0 / (1 + i + j) as f64
+            }
+        });
+        let b = vec![1.0; n];
+        let epsilon = 1e-10;
+        let predicted = jacobi::convergence(&a)
+            .unwrap()
+            .gauss_seidel_iterations(epsilon)
+            .unwrap();
+        let mut x = vec![0.0; n];
+        let actual = jacobi::iterate_on(&mut x, epsilon, &a, &b);
+        assert!(actual <= 2 * predicted + 2, "{} vs {}", actual, predicted);
+        assert!(predicted <= 2 * actual + 2, "{} vs {}", actual, predicted);
+        assert!(jacobi::convergence(&Array2d::<f64>::default(2, 3)).is_none());
+    }
+
+    #[test]
+    fn iterate_on_replaces_x() {
+        // one sweep from the exact solution has to stay there; adding the new
+        // value to x instead of replacing it would give [2.0, 1.5]
+        let a = Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 2.0]).unwrap();
+        let mut x = vec![1.0, 1.0];
+        let iterations = jacobi::iterate_on(&mut x, f64::INFINITY, &a, &[3.0, 3.0]);
+        assert_eq!(iterations, 1);
+        assert_eq!(x, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn iterate_on_converges() {
+        let n = 5;
+        let mut a = Array2d::gen(n, n, |i, j| if i == j { 0.0 } else { (i + j) as f64 });
+        for i in 0..n {
+            let sum: f64 = a.row(i).cloned().map(f64::abs).sum();
+            *a.at_mut(i, i).unwrap() = sum + 1.0;
+        }
+        let b: Vec<f64> = (0..n).map(|i| (i + 1) as f64).collect();
+        let mut x = vec![0.0; n];
+
+        assert!(jacobi::converges_for(&a));
+        let iterations = jacobi::iterate_on(&mut x, 1e-12, &a, &b);
+        assert!(iterations > 1);
+
+        let report = diagnostics::diagnose(&a, &x, &b).unwrap();
+        assert!(report.relative_residual < 1e-10, "{}", report);
+    }
+}
@@ -1,20 +1,44 @@
+pub mod cholesky;
+pub mod diagnostics;
 pub mod gauss;
+pub mod generators;
+pub mod io;
 pub mod jacobi;
+pub mod multiply;
+pub mod properties;
+pub mod solver;
+pub mod sparse;
+pub mod sweep;
+pub mod trace;
 
 mod array2d;
+mod compensated;
 mod iterators;
+mod literal;
 mod meow;
+mod ops;
+#[cfg(feature = "serde")]
+mod serialize;
 mod traits;
 mod transposed;
+mod triangular;
+mod view;
 
 pub mod indexes;
 
 pub use array2d::Array2d;
 pub use indexes::Indexable;
 pub use iterators::*;
+#[doc(hidden)]
+pub use literal::check_rows as __check_rows;
+pub use literal::LiteralError;
 pub use meow::Meow;
+pub use ops::matmul;
+pub use solver::{solve, Method, Options, Report};
 pub use traits::Matrix;
 pub use transposed::Transposed;
+pub use triangular::{Diagonal, LowerTriangular, UpperTriangular};
+pub use view::{View, ViewMut};
 
 pub fn multiply_diagonal<M: Matrix<Item = f64>>(m: &M) -> f64 {
     let mut product = 1.0;
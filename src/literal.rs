@@ -0,0 +1,275 @@
+use crate::Array2d;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiteralError {
+    // both 1-based, columns count characters
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl std::fmt::Display for LiteralError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for LiteralError {}
+
+#[derive(Clone, Copy)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    fn error<S: Into<String>>(self, message: S) -> LiteralError {
+        LiteralError {
+            line: self.line,
+            column: self.column,
+            message: message.into(),
+        }
+    }
+}
+
+enum Token<'a> {
+    Number(&'a str),
+    Open,
+    Close,
+    // `;` or a line break
+    RowEnd,
+}
+
+fn tokenize(text: &str) -> Vec<(Token<'_>, Position)> {
+    let mut tokens = vec![];
+    let mut position = Position { line: 1, column: 1 };
+    let mut start: Option<(usize, Position)> = None;
+    for (offset, c) in text.char_indices() {
+        let separator = c.is_whitespace() || matches!(c, ',' | ';' | '[' | ']');
+        if separator {
+            if let Some((begin, at)) = start.take() {
+                tokens.push((Token::Number(&text[begin..offset]), at));
+            }
+            match c {
+                '[' => tokens.push((Token::Open, position)),
+                ']' => tokens.push((Token::Close, position)),
+                ';' | '\n' => tokens.push((Token::RowEnd, position)),
+                _ => {}
+            }
+        } else if start.is_none() {
+            start = Some((offset, position));
+        }
+        if c == '\n' {
+            position = Position {
+                line: position.line + 1,
+                column: 1,
+            };
+        } else {
+            position.column += 1;
+        }
+    }
+    if let Some((begin, at)) = start {
+        tokens.push((Token::Number(&text[begin..]), at));
+    }
+    tokens
+}
+
+// decimal or scientific notation, `inf`, `nan`, or a fraction of two such numbers
+fn number(text: &str, at: Position) -> Result<f64, LiteralError> {
+    let invalid = || at.error(format!("invalid number `{}`", text));
+    let (numerator, denominator) = match text.split_once('/') {
+        Some((n, d)) => (n, Some(d)),
+        None => (text, None),
+    };
+    let numerator: f64 = numerator.parse().map_err(|_| invalid())?;
+    match denominator {
+        Some(d) => Ok(numerator / d.parse::<f64>().map_err(|_| invalid())?),
+        None => Ok(numerator),
+    }
+}
+
+// MATLAB-style `[1 2; 3 4]`, brackets optional, rows end at `;` or a line break
+impl FromStr for Array2d<f64> {
+    type Err = LiteralError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let tokens = tokenize(text);
+        // brackets may be surrounded by line breaks, as in piped input
+        let content = |(token, _): &(Token, Position)| !matches!(token, Token::RowEnd);
+        let first = tokens.iter().position(content).unwrap_or(0);
+        let last = tokens.iter().rposition(content).unwrap_or(0);
+        let mut rows = Rows {
+            width: None,
+            height: 0,
+            data: vec![],
+        };
+        let mut row = vec![];
+
+        for (index, (token, at)) in tokens.iter().enumerate() {
+            match token {
+                Token::Open if index == first => {}
+                Token::Close if index == last && matches!(tokens[first].0, Token::Open) => {}
+                Token::Open | Token::Close => {
+                    return Err(at.error("brackets may only enclose the whole matrix"))
+                }
+                Token::RowEnd => rows.end(&mut row, *at)?,
+                Token::Number(text) => {
+                    if rows.width == Some(row.len()) {
+                        return Err(at.error(format!(
+                            "row {} has more than {} elements",
+                            rows.height + 1,
+                            row.len()
+                        )));
+                    }
+                    row.push(number(text, *at)?);
+                }
+            }
+        }
+        if let Some((Token::Open, at)) = tokens.get(first) {
+            if first == last || !matches!(tokens[last].0, Token::Close) {
+                return Err(at.error("unclosed `[`"));
+            }
+        }
+        let end = tokens
+            .last()
+            .map_or(Position { line: 1, column: 1 }, |(_, at)| *at);
+        rows.end(&mut row, end)?;
+
+        let width = rows.width.unwrap_or(0);
+        Ok(Array2d::try_from(rows.height, width, rows.data)
+            .expect("Every row has `width` elements"))
+    }
+}
+
+struct Rows {
+    width: Option<usize>,
+    height: usize,
+    data: Vec<f64>,
+}
+
+impl Rows {
+    // empty rows from repeated separators are skipped
+    fn end(&mut self, row: &mut Vec<f64>, at: Position) -> Result<(), LiteralError> {
+        if row.is_empty() {
+            return Ok(());
+        }
+        match self.width {
+            Some(width) if width != row.len() => {
+                return Err(at.error(format!(
+                    "row {} has {} elements, expected {}",
+                    self.height + 1,
+                    row.len(),
+                    width
+                )))
+            }
+            _ => self.width = Some(row.len()),
+        }
+        self.height += 1;
+        self.data.append(row);
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+pub const fn check_rows(rows: &[usize]) {
+    let mut i = 1;
+    while i < rows.len() {
+        if rows[i] != rows[0] {
+            panic!("matrix! rows have different lengths");
+        }
+        i += 1;
+    }
+}
+
+/// Rows are separated by `;`, a length mismatch fails to compile:
+///
+/// ```compile_fail
+/// let a = matrices::matrix![1, 2; 3];
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {{
+        const ROWS: &[usize] = &[$($crate::matrix!(@count $($x),+)),+];
+        const _: () = $crate::__check_rows(ROWS);
+        $crate::Array2d::try_from(ROWS.len(), ROWS[0], vec![$($($x),+),+])
+            .ok()
+            .expect("Shape checked at compile time")
+    }};
+    (@count $($x:expr),+) => {
+        <[()]>::len(&[$($crate::matrix!(@unit $x)),+])
+    };
+    (@unit $x:expr) => {
+        ()
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Array2d, LiteralError, Matrix};
+
+    fn parse(text: &str) -> Result<Array2d<f64>, LiteralError> {
+        text.parse()
+    }
+
+    #[test]
+    fn literals() {
+        let a = parse("[1 2.5, -3; 4e-1 1/4 -1/2]").unwrap();
+        let expected = Array2d::try_from(2, 3, vec![1.0, 2.5, -3.0, 0.4, 0.25, -0.5]).unwrap();
+        assert_eq!(a, expected);
+
+        let b = parse("1, 2.5, -3\n0.4 1/4 -1/2\n").unwrap();
+        assert_eq!(b, expected);
+        assert_eq!(parse("\n[1 2.5 -3\n0.4 1/4 -1/2]\n").unwrap(), expected);
+
+        let c = parse("[inf -Inf; 1/0 NaN;]").unwrap();
+        assert_eq!(
+            c.to_vec()[..3],
+            [f64::INFINITY, f64::NEG_INFINITY, f64::INFINITY]
+        );
+        assert!(c.to_vec()[3].is_nan());
+
+        assert_eq!(parse("[]").unwrap().height(), 0);
+        assert_eq!(parse("").unwrap().width(), 0);
+        assert_eq!(parse("[1 1/3]").unwrap().to_vec(), vec![1.0, 1.0 / 3.0]);
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let error = parse("[1 2; 3 4 5]").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 11));
+        assert_eq!(error.message, "row 2 has more than 2 elements");
+
+        let error = parse("1 2 3\n4 5\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 4: row 2 has 2 elements, expected 3"
+        );
+
+        let error = parse("[1 2; 3 x]").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+        assert_eq!(error.message, "invalid number `x`");
+
+        assert_eq!(parse("[1 2; 3 1/]").unwrap_err().column, 9);
+        assert_eq!(parse("[1 [2]]").unwrap_err().column, 4);
+        assert_eq!(parse("[1 2").unwrap_err().column, 1);
+    }
+
+    #[test]
+    fn macro_builds_matrices() {
+        let a = matrix![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+        ];
+        assert_eq!(a, parse("[1 2 3; 4 5 6]").unwrap());
+        let b: Array2d<i32> = matrix![1, 2; 3, 4];
+        assert_eq!(b, Array2d::try_from(2, 2, vec![1, 2, 3, 4]).unwrap());
+        let column = matrix![1.0 / 3.0; 2.0];
+        assert_eq!((column.height(), column.width()), (2, 1));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{traits::Mapped, Array2d, Matrix};
+use crate::array2d::Map;
+use crate::{traits::Mapped, Array2d, Matrix, View};
 use either::{Either, Left, Right};
 
 #[derive(Clone)]
@@ -10,6 +11,7 @@ pub struct Meow<T> {
 
 #[allow(dead_code)]
 impl<M: Matrix<Item = T>, T> Meow<M> {
+    #[allow(clippy::useless_conversion)]
     pub fn eat(&mut self, snack: M) -> Result<(), M> {
         if self.height() == snack.height() {
             let old = self.width();
@@ -101,6 +103,32 @@ impl<M: Matrix<Item = T>, T> Meow<M> {
     }
 }
 
+impl<M> Meow<M> {
+    pub(crate) fn raw(&self) -> (&Map, &Map, &[M]) {
+        (&self.rows, &self.columns, &self.concat)
+    }
+
+    pub(crate) fn into_raw(self) -> (Map, Map, Vec<M>) {
+        (self.rows, self.columns, self.concat)
+    }
+
+    pub(crate) fn from_raw(rows: Map, columns: Map, concat: Vec<M>) -> Meow<M> {
+        Meow {
+            rows,
+            columns,
+            concat,
+        }
+    }
+}
+
+impl<M: Matrix<Item = T>, T> Meow<M> {
+    pub fn part(&self, index: usize) -> Option<View<'_, Meow<M>>> {
+        let total: usize = self.concat.iter().take(index).map(Matrix::width).sum();
+        let width = self.concat.get(index)?.width();
+        View::new(self, 0..self.height(), total..(total + width))
+    }
+}
+
 impl<T: Clone> Meow<Array2d<T>> {
     pub fn calculate(&self, index: usize) -> Option<Array2d<T>> {
         if index >= self.concat.len() {
@@ -208,6 +236,7 @@ impl<M: Matrix<Item = T>, T> Matrix for Meow<M> {
         }
     }
 
+    #[allow(clippy::useless_conversion)]
     fn swap_rows(&mut self, a: usize, b: usize) -> Option<()> {
         // let a = self.row(a)?;
         // let b = self.row(b)?;
@@ -224,6 +253,7 @@ impl<M: Matrix<Item = T>, T> Matrix for Meow<M> {
         Some(())
     }
 
+    #[allow(clippy::useless_conversion)]
     fn swap_columns(&mut self, a: usize, b: usize) -> Option<()> {
         // let a = self.column(a)?;
         // let b = self.column(b)?;
@@ -295,6 +325,24 @@ mod test {
         }
     }
 
+    #[test]
+    fn part_matches_calculate() {
+        let a = Array2d::gen(3, 3, |i, j| (i, j));
+        let b = Array2d::gen(3, 2, |i, j| (i, j + 3));
+        let mut m = Meow::from(a);
+        m.eat(b).unwrap();
+        m.swap_rows(0, 2);
+
+        let part = m.part(1).unwrap();
+        let copy = m.calculate(1).unwrap();
+        assert_eq!(part.height(), copy.height());
+        assert_eq!(part.width(), copy.width());
+        for (row, expected) in std::iter::zip(part.rows(), copy.rows()) {
+            assert!(row.eq(expected));
+        }
+        assert!(m.part(2).is_none());
+    }
+
     #[test]
     fn swap_rows_ok() {
         let size = 5;
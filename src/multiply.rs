@@ -0,0 +1,136 @@
+use crate::{Array2d, Matrix};
+use rayon::prelude::*;
+
+pub const BLOCK: usize = 64;
+
+// c[i][j] += alpha * a[i][p] * b[p][j] for row-major slices with leading dimensions
+// `ldc`, `lda` and `ldb`; row blocks of `c` are distributed across the rayon pool
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gemm(
+    c: &mut [f64],
+    ldc: usize,
+    a: &[f64],
+    lda: usize,
+    b: &[f64],
+    ldb: usize,
+    (m, n, k): (usize, usize, usize),
+    alpha: f64,
+    block: usize,
+) {
+    let block = block.max(1);
+    c.par_chunks_mut(ldc * block)
+        .enumerate()
+        .for_each(|(chunk, c)| {
+            let top = chunk * block;
+            if top >= m {
+                return;
+            }
+            let rows = block.min(m - top);
+            for kk in (0..k).step_by(block) {
+                let kend = (kk + block).min(k);
+                for jj in (0..n).step_by(block) {
+                    let jend = (jj + block).min(n);
+                    for i in 0..rows {
+                        let ci = &mut c[i * ldc + jj..i * ldc + jend];
+                        let ai = &a[(top + i) * lda..];
+                        for (p, &value) in ai.iter().enumerate().take(kend).skip(kk) {
+                            let factor = alpha * value;
+                            let bp = &b[p * ldb + jj..p * ldb + jend];
+                            for (x, y) in ci.iter_mut().zip(bp) {
+                                *x += factor * y;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+}
+
+pub fn naive(a: &Array2d<f64>, b: &Array2d<f64>) -> Option<Array2d<f64>> {
+    if a.width() != b.height() {
+        return None;
+    }
+    let (m, n, k) = (a.height(), b.width(), a.width());
+    let (a, b) = (a.to_vec(), b.to_vec());
+    let mut c = vec![0.0; m * n];
+    for i in 0..m {
+        for j in 0..n {
+            for p in 0..k {
+                c[i * n + j] += a[i * k + p] * b[p * n + j];
+            }
+        }
+    }
+    Array2d::try_from(m, n, c).ok()
+}
+
+pub fn blocked(a: &Array2d<f64>, b: &Array2d<f64>, block: usize) -> Option<Array2d<f64>> {
+    if a.width() != b.height() {
+        return None;
+    }
+    let (m, n, k) = (a.height(), b.width(), a.width());
+    let mut c = vec![0.0; m * n];
+    if m * n > 0 {
+        let a = a.to_vec();
+        let b = b.to_vec();
+        gemm(&mut c, n, &a, k, &b, n, (m, n, k), 1.0, block);
+    }
+    Array2d::try_from(m, n, c).ok()
+}
+
+pub fn gflops(m: usize, n: usize, k: usize, seconds: f64) -> f64 {
+    2.0 * (m * n * k) as f64 / seconds / 1e9
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{multiply, Array2d, Matrix};
+
+    fn sample(height: usize, width: usize, seed: usize) -> Array2d<f64> {
+        Array2d::gen(height, width, |i, j| {
+            ((i * 31 + j * 17 + seed) % 23) as f64 - 11.0
+        })
+    }
+
+    #[test]
+    fn blocked_matches_naive() {
+        for &(m, n, k) in &[(1, 1, 1), (7, 5, 3), (33, 65, 17), (100, 100, 100)] {
+            let a = sample(m, k, 1);
+            let b = sample(k, n, 2);
+            let expected = multiply::naive(&a, &b).unwrap();
+            for block in [1, 4, 16, multiply::BLOCK] {
+                assert_eq!(multiply::blocked(&a, &b, block).unwrap(), expected);
+            }
+            assert_eq!(crate::matmul(&a, &b).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn blocked_respects_permutations() {
+        let mut a = sample(20, 30, 3);
+        let mut b = sample(30, 10, 4);
+        a.swap_rows(0, 19);
+        b.swap_columns(2, 7);
+        b.swap_rows(1, 5);
+        let expected = crate::matmul(&a, &b).unwrap();
+        assert_eq!(
+            multiply::blocked(&a, &b, multiply::BLOCK).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn dimension_mismatch() {
+        let a = sample(3, 4, 0);
+        assert!(multiply::blocked(&a, &a, multiply::BLOCK).is_none());
+        assert!(multiply::naive(&a, &a).is_none());
+    }
+
+    #[test]
+    fn empty_product() {
+        let a = sample(0, 4, 0);
+        let b = sample(4, 3, 0);
+        let c = multiply::blocked(&a, &b, multiply::BLOCK).unwrap();
+        assert_eq!(c.height(), 0);
+        assert_eq!(c.width(), 3);
+    }
+}
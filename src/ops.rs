@@ -0,0 +1,301 @@
+use crate::{Array2d, Matrix};
+use num::{One, Zero};
+use std::ops::{Add, Mul, Neg, Sub};
+
+pub fn matmul<A, B, T>(a: &A, b: &B) -> Option<Array2d<T>>
+where
+    A: Matrix<Item = T>,
+    B: Matrix<Item = T>,
+    T: Clone + Zero + Mul<Output = T>,
+{
+    if a.width() != b.height() {
+        return None;
+    }
+    let mut data = Vec::with_capacity(a.height() * b.width());
+    for i in 0..a.height() {
+        for j in 0..b.width() {
+            let mut sum = T::zero();
+            for k in 0..a.width() {
+                sum = sum + a.at(i, k)?.clone() * b.at(k, j)?.clone();
+            }
+            data.push(sum);
+        }
+    }
+    Array2d::try_from(a.height(), b.width(), data).ok()
+}
+
+fn zip_with<T: Clone, F: Fn(T, T) -> T>(
+    a: &Array2d<T>,
+    b: &Array2d<T>,
+    f: F,
+) -> Option<Array2d<T>> {
+    if a.height() != b.height() || a.width() != b.width() {
+        return None;
+    }
+    let mut data = Vec::with_capacity(a.height() * a.width());
+    for i in 0..a.height() {
+        for j in 0..a.width() {
+            data.push(f(a.at(i, j)?.clone(), b.at(i, j)?.clone()));
+        }
+    }
+    Array2d::try_from(a.height(), a.width(), data).ok()
+}
+
+impl<T: Zero + One> Array2d<T> {
+    pub fn identity(n: usize) -> Array2d<T> {
+        Array2d::gen(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+impl<T: Clone> Array2d<T> {
+    pub fn map<U, F: Fn(&T) -> U>(&self, f: F) -> Array2d<U> {
+        Array2d::gen(self.height(), self.width(), |i, j| {
+            f(self.at(i, j).expect("Index within own dimensions"))
+        })
+    }
+}
+
+impl<T: Clone + Add<Output = T>> Array2d<T> {
+    pub fn try_add(&self, other: &Array2d<T>) -> Option<Array2d<T>> {
+        zip_with(self, other, T::add)
+    }
+}
+
+impl<T: Clone + Sub<Output = T>> Array2d<T> {
+    pub fn try_sub(&self, other: &Array2d<T>) -> Option<Array2d<T>> {
+        zip_with(self, other, T::sub)
+    }
+}
+
+impl<T: Clone + Zero + Mul<Output = T>> Array2d<T> {
+    pub fn try_mul(&self, other: &Array2d<T>) -> Option<Array2d<T>> {
+        matmul(self, other)
+    }
+
+    pub fn try_mul_vec(&self, x: &[T]) -> Option<Vec<T>> {
+        if self.width() != x.len() {
+            return None;
+        }
+        let mut y = Vec::with_capacity(self.height());
+        for i in 0..self.height() {
+            let mut sum = T::zero();
+            for (j, value) in x.iter().enumerate() {
+                sum = sum + self.at(i, j)?.clone() * value.clone();
+            }
+            y.push(sum);
+        }
+        Some(y)
+    }
+}
+
+impl<T: Clone + Add<Output = T>> Add<&Array2d<T>> for &Array2d<T> {
+    type Output = Array2d<T>;
+
+    fn add(self, rhs: &Array2d<T>) -> Self::Output {
+        self.try_add(rhs)
+            .expect("Dimensions do not match for addition")
+    }
+}
+
+impl<T: Clone + Sub<Output = T>> Sub<&Array2d<T>> for &Array2d<T> {
+    type Output = Array2d<T>;
+
+    fn sub(self, rhs: &Array2d<T>) -> Self::Output {
+        self.try_sub(rhs)
+            .expect("Dimensions do not match for subtraction")
+    }
+}
+
+impl<T: Clone + Zero + Mul<Output = T>> Mul<&Array2d<T>> for &Array2d<T> {
+    type Output = Array2d<T>;
+
+    fn mul(self, rhs: &Array2d<T>) -> Self::Output {
+        self.try_mul(rhs)
+            .expect("Dimensions do not match for multiplication")
+    }
+}
+
+impl<T: Clone + Zero + Mul<Output = T>> Mul<&[T]> for &Array2d<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, rhs: &[T]) -> Self::Output {
+        self.try_mul_vec(rhs)
+            .expect("Dimensions do not match for multiplication")
+    }
+}
+
+impl<T: Clone + Zero + Mul<Output = T>> Mul<&Vec<T>> for &Array2d<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, rhs: &Vec<T>) -> Self::Output {
+        self * rhs.as_slice()
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> Neg for &Array2d<T> {
+    type Output = Array2d<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|value| -value.clone())
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> Neg for Array2d<T> {
+    type Output = Array2d<T>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+macro_rules! owned {
+    ($op:ident, $method:ident, $($bound:tt)+) => {
+        impl<T: $($bound)+> $op<Array2d<T>> for Array2d<T> {
+            type Output = Array2d<T>;
+
+            fn $method(self, rhs: Array2d<T>) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<T: $($bound)+> $op<&Array2d<T>> for Array2d<T> {
+            type Output = Array2d<T>;
+
+            fn $method(self, rhs: &Array2d<T>) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T: $($bound)+> $op<Array2d<T>> for &Array2d<T> {
+            type Output = Array2d<T>;
+
+            fn $method(self, rhs: Array2d<T>) -> Self::Output {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+owned!(Add, add, Clone + Add<Output = T>);
+owned!(Sub, sub, Clone + Sub<Output = T>);
+owned!(Mul, mul, Clone + Zero + Mul<Output = T>);
+
+macro_rules! scalar {
+    ($($t:ty),+) => {$(
+        impl Mul<$t> for &Array2d<$t> {
+            type Output = Array2d<$t>;
+
+            fn mul(self, rhs: $t) -> Self::Output {
+                self.map(|value| value * rhs)
+            }
+        }
+
+        impl Mul<$t> for Array2d<$t> {
+            type Output = Array2d<$t>;
+
+            fn mul(self, rhs: $t) -> Self::Output {
+                &self * rhs
+            }
+        }
+
+        impl Mul<&Array2d<$t>> for $t {
+            type Output = Array2d<$t>;
+
+            fn mul(self, rhs: &Array2d<$t>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Mul<Array2d<$t>> for $t {
+            type Output = Array2d<$t>;
+
+            fn mul(self, rhs: Array2d<$t>) -> Self::Output {
+                &rhs * self
+            }
+        }
+    )+};
+}
+
+scalar!(f32, f64, i32, i64);
+
+#[cfg(test)]
+mod test {
+    use crate::{matmul, Array2d, Matrix, Meow, Transposed};
+
+    fn a() -> Array2d<f64> {
+        Array2d::try_from(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap()
+    }
+
+    #[test]
+    fn add_sub_neg_ok() {
+        let a = a();
+        let b = Array2d::gen(2, 3, |i, j| (i + j) as f64);
+        let sum = &a + &b;
+        assert_eq!(
+            sum,
+            Array2d::gen(2, 3, |i, j| (3 * i + j + 1 + i + j) as f64)
+        );
+        assert_eq!(&sum - &b, a);
+        assert_eq!(-&a + a.clone(), Array2d::default(2, 3));
+        assert!(a.try_add(&Array2d::default(3, 2)).is_none());
+    }
+
+    #[test]
+    fn matrix_product_ok() {
+        let a = a();
+        let product = &a * &Array2d::gen(3, 2, |i, j| if i == j { 1.0 } else { 0.0 });
+        let expected = Array2d::try_from(2, 2, vec![1.0, 2.0, 4.0, 5.0]).unwrap();
+        assert_eq!(product, expected);
+        assert!(a.try_mul(&a).is_none());
+    }
+
+    #[test]
+    fn vector_and_scalar_product_ok() {
+        let a = a();
+        assert_eq!(&a * &vec![1.0, 1.0, 1.0], vec![6.0, 15.0]);
+        assert_eq!(2.0 * &a, &a + &a);
+        assert!(a.try_mul_vec(&[1.0]).is_none());
+    }
+
+    #[test]
+    fn product_respects_permutations() {
+        let mut a = a();
+        a.swap_rows(0, 1);
+        let e = Array2d::<f64>::identity(3);
+        assert_eq!(&a * &e, a);
+    }
+
+    #[test]
+    fn matmul_with_transposed() {
+        let a = a();
+        let ata = matmul(&Transposed::new(&a), &a).unwrap();
+        let expected = Array2d::try_from(
+            3,
+            3,
+            vec![
+                17.0, 22.0, 27.0, //
+                22.0, 29.0, 36.0, //
+                27.0, 36.0, 45.0, //
+            ],
+        )
+        .unwrap();
+        assert_eq!(ata, expected);
+    }
+
+    #[test]
+    fn matmul_with_meow() {
+        let mut m = Meow::from(Array2d::<f64>::identity(2));
+        m.eat(Array2d::try_from(2, 1, vec![3.0, 4.0]).unwrap())
+            .unwrap();
+        let product = matmul(&Transposed::new(&m), &m).unwrap();
+        assert_eq!(product.at(2, 2), Some(&25.0));
+        assert_eq!(product.at(0, 2), Some(&3.0));
+    }
+
+    #[test]
+    fn inverse_check() {
+        let a = Array2d::try_from(2, 2, vec![4.0, 7.0, 2.0, 6.0]).unwrap();
+        let residual = &(&a * &crate::inversed(a.clone())) - &Array2d::identity(2);
+        assert!(crate::inf_norm(&residual) < 1e-12);
+    }
+}
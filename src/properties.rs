@@ -0,0 +1,210 @@
+use crate::{cholesky, Matrix};
+
+pub const TOLERANCE: f64 = 1e-12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dominance {
+    None,
+    Weak,
+    Strict,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Properties {
+    pub height: usize,
+    pub width: usize,
+    pub symmetric: bool,
+    pub row_dominance: Dominance,
+    pub column_dominance: Dominance,
+    pub lower_bandwidth: usize,
+    pub upper_bandwidth: usize,
+    pub nonzeros: usize,
+    pub positive_definite: bool,
+    pub zero_diagonals: Vec<usize>,
+}
+
+impl Properties {
+    pub fn square(&self) -> bool {
+        self.height == self.width
+    }
+
+    pub fn upper_triangular(&self) -> bool {
+        self.lower_bandwidth == 0
+    }
+
+    pub fn lower_triangular(&self) -> bool {
+        self.upper_bandwidth == 0
+    }
+
+    pub fn diagonal(&self) -> bool {
+        self.upper_triangular() && self.lower_triangular()
+    }
+
+    pub fn tridiagonal(&self) -> bool {
+        self.lower_bandwidth <= 1 && self.upper_bandwidth <= 1
+    }
+
+    // share of zero entries
+    pub fn sparsity(&self) -> f64 {
+        let total = self.height * self.width;
+        if total == 0 {
+            0.0
+        } else {
+            1.0 - self.nonzeros as f64 / total as f64
+        }
+    }
+}
+
+fn dominance<F: Fn(usize) -> (f64, f64)>(n: usize, split: F) -> Dominance {
+    let mut result = Dominance::Strict;
+    for i in 0..n {
+        let (diag, rest) = split(i);
+        if diag < rest {
+            return Dominance::None;
+        } else if diag == rest {
+            result = Dominance::Weak;
+        }
+    }
+    result
+}
+
+pub fn analyze<M: Matrix<Item = f64>>(m: &M) -> Properties {
+    analyze_with(m, TOLERANCE)
+}
+
+// `tolerance` is relative, |a_ij - a_ji| <= tolerance * max(|a_ij|, |a_ji|)
+pub fn analyze_with<M: Matrix<Item = f64>>(m: &M, tolerance: f64) -> Properties {
+    let h = m.height();
+    let w = m.width();
+    let at = |i: usize, j: usize| *m.at(i, j).expect("Index within matrix dimensions");
+
+    let mut nonzeros = 0;
+    let mut lower_bandwidth = 0;
+    let mut upper_bandwidth = 0;
+    // off-diagonal sums, subtracting the diagonal from a full sum would round
+    let mut row_sums = vec![0.0; h];
+    let mut column_sums = vec![0.0; w];
+    for (i, row_sum) in row_sums.iter_mut().enumerate() {
+        for (j, column_sum) in column_sums.iter_mut().enumerate() {
+            let value = at(i, j);
+            if value != 0.0 {
+                nonzeros += 1;
+                lower_bandwidth = lower_bandwidth.max(i.saturating_sub(j));
+                upper_bandwidth = upper_bandwidth.max(j.saturating_sub(i));
+            }
+            if i != j {
+                *row_sum += value.abs();
+                *column_sum += value.abs();
+            }
+        }
+    }
+
+    let square = h == w;
+    let symmetric = square
+        && (0..h).all(|i| {
+            (0..i).all(|j| {
+                let (a, b) = (at(i, j), at(j, i));
+                (a - b).abs() <= tolerance * a.abs().max(b.abs())
+            })
+        });
+
+    let (row_dominance, column_dominance) = if square {
+        (
+            dominance(h, |i| (at(i, i).abs(), row_sums[i])),
+            dominance(w, |j| (at(j, j).abs(), column_sums[j])),
+        )
+    } else {
+        (Dominance::None, Dominance::None)
+    };
+
+    let zero_diagonals = (0..h.min(w)).filter(|&i| at(i, i) == 0.0).collect();
+    let positive_definite = symmetric && cholesky::factor(m).is_some();
+
+    Properties {
+        height: h,
+        width: w,
+        symmetric,
+        row_dominance,
+        column_dominance,
+        lower_bandwidth,
+        upper_bandwidth,
+        nonzeros,
+        positive_definite,
+        zero_diagonals,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::properties::{analyze, analyze_with, Dominance};
+    use crate::{generators, Array2d, Transposed};
+
+    #[test]
+    fn course_matrix() {
+        let a = generators::course(5);
+        let p = analyze(&a);
+        assert!(p.square());
+        assert!(!p.symmetric);
+        assert!(!p.positive_definite);
+        assert_eq!(p.row_dominance, Dominance::None);
+        assert_eq!(p.zero_diagonals, vec![1, 2, 3, 4]);
+        assert_eq!((p.lower_bandwidth, p.upper_bandwidth), (4, 4));
+        assert_eq!(p.nonzeros, 21);
+    }
+
+    #[test]
+    fn poisson_matrix() {
+        let n = 6;
+        let a = generators::poisson_1d(n);
+        let p = analyze(&a);
+        assert!(p.symmetric);
+        assert!(p.positive_definite);
+        assert!(p.tridiagonal());
+        assert!(!p.diagonal());
+        assert_eq!(p.row_dominance, Dominance::Weak);
+        assert_eq!(p.column_dominance, Dominance::Weak);
+        assert!((p.sparsity() - (1.0 - 16.0 / 36.0)).abs() < 1e-12);
+        assert!(p.zero_diagonals.is_empty());
+    }
+
+    #[test]
+    fn weak_dominance_is_exact() {
+        // 0.6 + 0.5 + 0.1 - 0.6 rounds to 0.6000000000000002
+        let a = Array2d::try_from(3, 3, vec![0.6, 0.5, 0.1, 0.5, 0.6, 0.1, 0.1, 0.5, 0.6]).unwrap();
+        assert_eq!(analyze(&a).row_dominance, Dominance::Weak);
+    }
+
+    #[test]
+    fn triangular_and_dominance() {
+        let a = Array2d::try_from(3, 3, vec![2.0, 1.0, 1.0, 0.0, 3.0, 4.0, 0.0, 0.0, 6.0]).unwrap();
+        let p = analyze(&a);
+        assert!(p.upper_triangular());
+        assert!(!p.lower_triangular());
+        assert_eq!(p.upper_bandwidth, 2);
+        assert_eq!(p.row_dominance, Dominance::None);
+        assert_eq!(p.column_dominance, Dominance::Strict);
+
+        let p = analyze(&Transposed::new(&a));
+        assert!(p.lower_triangular());
+        assert_eq!(p.row_dominance, Dominance::Strict);
+    }
+
+    #[test]
+    fn symmetry_tolerance() {
+        let a = Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0 + 1e-9, 2.0]).unwrap();
+        assert!(!analyze(&a).symmetric);
+        assert!(analyze_with(&a, 1e-6).symmetric);
+        assert!(analyze_with(&a, 1e-6).positive_definite);
+    }
+
+    #[test]
+    fn rectangular() {
+        let p = analyze(&Array2d::<f64>::default(2, 3));
+        assert!(!p.square());
+        assert!(!p.symmetric);
+        assert_eq!(p.sparsity(), 1.0);
+        assert_eq!(p.zero_diagonals, vec![0, 1]);
+    }
+}
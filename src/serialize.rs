@@ -0,0 +1,209 @@
+use crate::{Array2d, Matrix, Meow, Transposed};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// row-major contents as seen through `at`, with every permutation applied
+#[derive(Serialize)]
+struct Logical<'a, T> {
+    height: usize,
+    width: usize,
+    data: Vec<&'a T>,
+}
+
+#[derive(Deserialize)]
+struct Owned<T> {
+    height: usize,
+    width: usize,
+    data: Vec<T>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Parts<P> {
+    parts: Vec<P>,
+}
+
+fn logical<M: Matrix<Item = T>, T>(m: &M, columns: std::ops::Range<usize>) -> Logical<'_, T> {
+    let mut data = Vec::with_capacity(m.height() * columns.len());
+    for i in 0..m.height() {
+        for j in columns.clone() {
+            data.push(m.at(i, j).expect("Index within matrix dimensions"));
+        }
+    }
+    Logical {
+        height: m.height(),
+        width: columns.len(),
+        data,
+    }
+}
+
+impl<T> Owned<T> {
+    fn into_array<E: Error>(self) -> Result<Array2d<T>, E> {
+        let (height, width) = (self.height, self.width);
+        // untrusted, `try_from` would overflow on it
+        let count = height
+            .checked_mul(width)
+            .ok_or_else(|| E::custom(format!("{}x{} matrix is too large", height, width)))?;
+        Array2d::try_from(height, width, self.data).map_err(|data| {
+            E::custom(format!(
+                "{}x{} matrix needs {} values, found {}",
+                height,
+                width,
+                count,
+                data.len()
+            ))
+        })
+    }
+}
+
+impl<T: Serialize> Serialize for Array2d<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        logical(self, 0..self.width()).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Array2d<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Owned::deserialize(deserializer)?.into_array()
+    }
+}
+
+impl<M: Matrix<Item = T>, T: Serialize> Serialize for Transposed<M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        logical(self, 0..self.width()).serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Clone> Deserialize<'de> for Transposed<Array2d<T>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let a = Owned::<T>::deserialize(deserializer)?.into_array::<D::Error>()?;
+        let origin = Array2d::gen(a.width(), a.height(), |i, j| {
+            a.at(j, i).expect("Index within matrix dimensions").clone()
+        });
+        Ok(Transposed::new(origin))
+    }
+}
+
+// each part holds its own columns of the permuted whole
+impl<M: Matrix<Item = T>, T: Serialize> Serialize for Meow<M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut parts = vec![];
+        let mut total = 0;
+        for part in self.raw().2 {
+            parts.push(logical(self, total..total + part.width()));
+            total += part.width();
+        }
+        Parts { parts }.serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Meow<Array2d<T>> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut parts = Parts::<Owned<T>>::deserialize(deserializer)?
+            .parts
+            .into_iter();
+        let first = parts
+            .next()
+            .ok_or_else(|| D::Error::custom("a Meow needs at least one part"))?;
+        let mut meow = Meow::from(first.into_array::<D::Error>()?);
+        for (index, part) in parts.enumerate() {
+            let height = part.height;
+            meow.eat(part.into_array::<D::Error>()?).map_err(|_| {
+                D::Error::custom(format!(
+                    "part {} has {} rows, expected {}",
+                    index + 1,
+                    height,
+                    meow.height()
+                ))
+            })?;
+        }
+        Ok(meow)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::solver::{Method, Options, Report};
+    use crate::{Array2d, Matrix, Meow, Transposed};
+
+    #[test]
+    fn array_uses_logical_order() {
+        let mut a = Array2d::try_from(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        a.swap_rows(0, 1);
+        a.swap_columns(0, 2);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(
+            json,
+            r#"{"height":2,"width":3,"data":[6.0,5.0,4.0,3.0,2.0,1.0]}"#
+        );
+
+        let b: Array2d<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(b, a);
+        assert!(b.as_slice().is_some());
+
+        let error = serde_json::from_str::<Array2d<f64>>(r#"{"height":2,"width":2,"data":[1]}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.starts_with("2x2 matrix needs 4 values, found 1"),
+            "{}",
+            error
+        );
+
+        let huge = r#"{"height":4294967296,"width":4294967296,"data":[]}"#;
+        let error = serde_json::from_str::<Array2d<f64>>(huge)
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.starts_with("4294967296x4294967296 matrix is too large"),
+            "{}",
+            error
+        );
+    }
+
+    #[test]
+    fn transposed_and_meow() {
+        let a = Array2d::try_from(2, 3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let t = Transposed::new(a.clone());
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(json, r#"{"height":3,"width":2,"data":[1,4,2,5,3,6]}"#);
+        let back: Transposed<Array2d<i32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.into_inner(), a);
+
+        let mut m = Meow::from(Array2d::try_from(2, 2, vec![1, 2, 3, 4]).unwrap());
+        m.eat(Array2d::try_from(2, 1, vec![5, 6]).unwrap())
+            .ok()
+            .unwrap();
+        m.swap_rows(0, 1);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(
+            json,
+            r#"{"parts":[{"height":2,"width":2,"data":[3,4,1,2]},{"height":2,"width":1,"data":[6,5]}]}"#
+        );
+        let back: Meow<Array2d<i32>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.calculate(0), m.calculate(0));
+        assert_eq!(back.calculate(1), m.calculate(1));
+
+        let uneven =
+            r#"{"parts":[{"height":2,"width":1,"data":[1,2]},{"height":1,"width":1,"data":[3]}]}"#;
+        assert!(serde_json::from_str::<Meow<Array2d<i32>>>(uneven).is_err());
+        assert!(serde_json::from_str::<Meow<Array2d<i32>>>(r#"{"parts":[]}"#).is_err());
+    }
+
+    #[test]
+    fn solver_types() {
+        let options: Options = serde_json::from_str(r#"{"method":"Cholesky"}"#).unwrap();
+        assert_eq!(
+            options,
+            Options {
+                method: Some(Method::Cholesky),
+                ..Options::default()
+            }
+        );
+
+        let a = Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 3.0]).unwrap();
+        let report = crate::solve(&a, &[3.0, 4.0], options).unwrap();
+        let json = serde_json::to_string(&report).unwrap();
+        let back: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, report);
+    }
+}
@@ -0,0 +1,399 @@
+use crate::gauss::{Lu, SolveError};
+use crate::properties::{self, Dominance, Properties};
+use crate::{cholesky, jacobi, Array2d, Diagonal, LowerTriangular, Matrix, UpperTriangular};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Method {
+    Diagonal,
+    BackSubstitution,
+    ForwardSubstitution,
+    Thomas,
+    Cholesky,
+    Gauss,
+    Iterative,
+}
+
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Method::Diagonal => "diagonal",
+            Method::BackSubstitution => "back substitution",
+            Method::ForwardSubstitution => "forward substitution",
+            Method::Thomas => "Thomas",
+            Method::Cholesky => "Cholesky",
+            Method::Gauss => "Gaussian elimination with partial pivoting",
+            Method::Iterative => "Gauss-Seidel iteration",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Method {
+    pub const ALL: [Method; 7] = [
+        Method::Diagonal,
+        Method::BackSubstitution,
+        Method::ForwardSubstitution,
+        Method::Thomas,
+        Method::Cholesky,
+        Method::Gauss,
+        Method::Iterative,
+    ];
+
+    // short name accepted by `from_str`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Method::Diagonal => "diagonal",
+            Method::BackSubstitution => "back",
+            Method::ForwardSubstitution => "forward",
+            Method::Thomas => "thomas",
+            Method::Cholesky => "cholesky",
+            Method::Gauss => "gauss",
+            Method::Iterative => "iterative",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownMethod(pub String);
+
+impl std::fmt::Display for UnknownMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let names: Vec<&str> = Method::ALL.iter().map(Method::name).collect();
+        write!(
+            f,
+            "unknown method `{}`, expected one of {}",
+            self.0,
+            names.join(", ")
+        )
+    }
+}
+
+impl std::error::Error for UnknownMethod {}
+
+impl std::str::FromStr for Method {
+    type Err = UnknownMethod;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Method::ALL
+            .into_iter()
+            .find(|m| m.name().eq_ignore_ascii_case(s) || m.to_string().eq_ignore_ascii_case(s))
+            .ok_or_else(|| UnknownMethod(s.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Options {
+    // skips the selection altogether
+    pub method: Option<Method>,
+    // systems at least this large are solved iteratively when strictly diagonally dominant
+    pub iterative_threshold: usize,
+    pub epsilon: f64,
+    // the iterative method gives up after this many sweeps
+    pub max_iterations: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            method: None,
+            iterative_threshold: 500,
+            epsilon: 1e-12,
+            max_iterations: 10_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Report {
+    pub x: Vec<f64>,
+    pub method: Method,
+    pub reason: String,
+    pub iterations: Option<usize>,
+    pub properties: Properties,
+}
+
+pub fn select(p: &Properties, options: &Options) -> (Method, String) {
+    if let Some(method) = options.method {
+        return (method, "requested in options".to_string());
+    }
+    let n = p.height;
+    if p.diagonal() {
+        (Method::Diagonal, "the matrix is diagonal".to_string())
+    } else if p.upper_triangular() {
+        (
+            Method::BackSubstitution,
+            "the matrix is upper triangular".to_string(),
+        )
+    } else if p.lower_triangular() {
+        (
+            Method::ForwardSubstitution,
+            "the matrix is lower triangular".to_string(),
+        )
+    } else if p.tridiagonal() && p.row_dominance != Dominance::None {
+        (
+            Method::Thomas,
+            "the matrix is tridiagonal and diagonally dominant".to_string(),
+        )
+    } else if p.tridiagonal() && p.positive_definite {
+        (
+            Method::Thomas,
+            "the matrix is tridiagonal and positive definite".to_string(),
+        )
+    } else if n >= options.iterative_threshold && p.row_dominance == Dominance::Strict {
+        (
+            Method::Iterative,
+            format!(
+                "n = {} is at least {} and the matrix is strictly diagonally dominant",
+                n, options.iterative_threshold
+            ),
+        )
+    } else if p.positive_definite {
+        (
+            Method::Cholesky,
+            "the matrix is symmetric positive definite".to_string(),
+        )
+    } else {
+        (
+            Method::Gauss,
+            "the matrix has no exploitable structure".to_string(),
+        )
+    }
+}
+
+// no pivoting, stable for diagonally dominant or positive definite matrices
+pub fn thomas<M: Matrix<Item = f64>>(m: &M, b: &mut [f64]) -> Option<()> {
+    let n = m.height();
+    if n != m.width() || n != b.len() {
+        return None;
+    }
+    if n == 0 {
+        return Some(());
+    }
+    let mut c = vec![0.0; n];
+    let mut diag = *m.at(0, 0)?;
+    for i in 0..n {
+        if i > 0 {
+            let lower = *m.at(i, i - 1)?;
+            diag = *m.at(i, i)? - lower * c[i - 1];
+            b[i] -= lower * b[i - 1];
+        }
+        if diag == 0.0 {
+            return None;
+        }
+        if i + 1 < n {
+            c[i] = *m.at(i, i + 1)? / diag;
+        }
+        b[i] /= diag;
+    }
+    for i in (0..n - 1).rev() {
+        b[i] -= c[i] * b[i + 1];
+    }
+    Some(())
+}
+
+pub fn solve(a: &Array2d<f64>, b: &[f64], options: Options) -> Result<Report, SolveError> {
+    if a.height() != a.width() {
+        return Err(SolveError::NotSquare {
+            height: a.height(),
+            width: a.width(),
+        });
+    }
+    if a.height() != b.len() {
+        return Err(SolveError::Dimensions {
+            height: a.height(),
+            width: a.width(),
+            rhs: b.len(),
+        });
+    }
+
+    let properties = properties::analyze(a);
+    let (method, reason) = select(&properties, &options);
+
+    let mut x = b.to_vec();
+    let mut iterations = None;
+    let solved = match method {
+        Method::Diagonal => Diagonal::new(a).solve(&mut x),
+        Method::BackSubstitution => UpperTriangular::new(a).solve(&mut x),
+        Method::ForwardSubstitution => LowerTriangular::new(a).solve(&mut x),
+        Method::Thomas => thomas(a, &mut x).ok_or(SolveError::ZeroPivot)?.into(),
+        Method::Cholesky => {
+            // `cholesky::factor` only reads the lower triangle
+            if !properties.symmetric {
+                return Err(SolveError::NotPositiveDefinite);
+            }
+            cholesky::solve(a, &mut x)
+                .ok_or(SolveError::NotPositiveDefinite)?
+                .into()
+        }
+        Method::Gauss => Lu::new(a)?.solve(&mut x),
+        Method::Iterative => {
+            if let Some(&index) = properties.zero_diagonals.first() {
+                return Err(SolveError::ZeroDiagonal { index });
+            }
+            x.iter_mut().for_each(|x| *x = 0.0);
+            let sweeps =
+                jacobi::iterate_at_most(&mut x, options.epsilon, a, b, options.max_iterations)
+                    .map_err(|iterations| SolveError::NotConverged { iterations })?;
+            iterations = Some(sweeps);
+            Some(())
+        }
+    };
+
+    if solved.is_none() || x.iter().any(|x| !x.is_finite()) {
+        return Err(SolveError::Singular);
+    }
+
+    Ok(Report {
+        x,
+        method,
+        reason,
+        iterations,
+        properties,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gauss::SolveError;
+    use crate::solver::{thomas, Method, Options};
+    use crate::{generators, Array2d, Matrix};
+
+    fn check(a: &Array2d<f64>, options: Options) -> Method {
+        let exact: Vec<f64> = (0..a.width()).map(|i| (i + 1) as f64).collect();
+        let b = a.try_mul_vec(&exact).unwrap();
+        let report = crate::solve(a, &b, options).unwrap();
+        for (x, e) in report.x.iter().zip(&exact) {
+            assert!((x - e).abs() < 1e-8, "{}: {} != {}", report.method, x, e);
+        }
+        report.method
+    }
+
+    #[test]
+    fn picks_structured_methods() {
+        let d = Array2d::gen(3, 3, |i, j| if i == j { (i + 1) as f64 } else { 0.0 });
+        assert_eq!(check(&d, Options::default()), Method::Diagonal);
+
+        let u = Array2d::gen(4, 4, |i, j| if i <= j { (i + j + 1) as f64 } else { 0.0 });
+        assert_eq!(check(&u, Options::default()), Method::BackSubstitution);
+
+        let l = Array2d::gen(4, 4, |i, j| if i >= j { (i + j + 1) as f64 } else { 0.0 });
+        assert_eq!(check(&l, Options::default()), Method::ForwardSubstitution);
+
+        assert_eq!(
+            check(&generators::poisson_1d(10), Options::default()),
+            Method::Thomas
+        );
+    }
+
+    #[test]
+    fn picks_cholesky_and_gauss() {
+        let spd = Array2d::gen(5, 5, |i, j| {
+            if i == j {
+                10.0
+            } else {
+                1.0 / (i + j + 1) as f64
+            }
+        });
+        assert_eq!(check(&spd, Options::default()), Method::Cholesky);
+
+        let a = generators::course(5);
+        assert_eq!(check(&a, Options::default()), Method::Gauss);
+    }
+
+    #[test]
+    fn picks_iterative_for_large_dominant() {
+        let n = 30;
+        let a = Array2d::gen(n, n, |i, j| {
+            if i == j {
+                4.0 * n as f64
+            } else {
+                ((i * j) % 5) as f64
+            }
+        });
+        let options = Options {
+            iterative_threshold: 20,
+            ..Options::default()
+        };
+        assert_eq!(check(&a, options.clone()), Method::Iterative);
+        let b = vec![1.0; n];
+        assert!(crate::solve(&a, &b, options).unwrap().iterations.unwrap() > 0);
+        assert_eq!(check(&a, Options::default()), Method::Cholesky);
+    }
+
+    #[test]
+    fn forced_method_and_errors() {
+        let options = Options {
+            method: Some(Method::Gauss),
+            ..Options::default()
+        };
+        let report = crate::solve(&generators::poisson_1d(4), &[1.0; 4], options).unwrap();
+        assert_eq!(report.method, Method::Gauss);
+        assert_eq!(report.reason, "requested in options");
+
+        assert_eq!("Cholesky".parse(), Ok(Method::Cholesky));
+        assert_eq!("back substitution".parse(), Ok(Method::BackSubstitution));
+        for method in Method::ALL {
+            assert_eq!(method.name().parse(), Ok(method));
+        }
+        assert!("lu".parse::<Method>().is_err());
+
+        let singular = Array2d::try_from(2, 2, vec![1.0, 1.0, 0.0, 0.0]).unwrap();
+        assert!(crate::solve(&singular, &[1.0, 1.0], Options::default()).is_err());
+        assert!(crate::solve(&generators::poisson_1d(3), &[1.0], Options::default()).is_err());
+    }
+
+    #[test]
+    fn failures_are_told_apart() {
+        let forced = |method| Options {
+            method: Some(method),
+            ..Options::default()
+        };
+        let a = Array2d::try_from(2, 2, vec![1.0, 3.0, 3.0, 1.0]).unwrap();
+        // overflows long before the cap is reached
+        assert!(matches!(
+            crate::solve(&a, &[1.0, 1.0], forced(Method::Iterative)),
+            Err(SolveError::NotConverged { iterations }) if iterations < 10_000
+        ));
+        // only the lower triangle would be read, the upper one must not be ignored
+        let a = Array2d::try_from(2, 2, vec![2.0, 5.0, 1.0, 3.0]).unwrap();
+        assert_eq!(
+            crate::solve(&a, &[1.0, 1.0], forced(Method::Cholesky)),
+            Err(SolveError::NotPositiveDefinite)
+        );
+        let a = Array2d::try_from(2, 2, vec![0.0, 1.0, 1.0, 0.0]).unwrap();
+        assert_eq!(
+            crate::solve(&a, &[1.0, 1.0], forced(Method::Thomas)),
+            Err(SolveError::ZeroPivot)
+        );
+        assert_eq!(
+            crate::solve(&a, &[1.0, 1.0], forced(Method::Iterative)),
+            Err(SolveError::ZeroDiagonal { index: 0 })
+        );
+        assert_eq!(
+            crate::solve(&a, &[1.0, 1.0], forced(Method::Gauss))
+                .unwrap()
+                .x,
+            vec![1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn thomas_matches_gauss() {
+        let n = 8;
+        let a = Array2d::gen(n, n, |i, j| match j as isize - i as isize {
+            0 => 4.0 + i as f64,
+            -1 => 1.0,
+            1 => -2.0,
+            _ => 0.0,
+        });
+        let b: Vec<f64> = (0..n).map(|i| i as f64).collect();
+        let mut x = b.clone();
+        thomas(&a, &mut x).unwrap();
+        let residual = crate::diagnostics::residual(&a, &x, &b).unwrap();
+        assert!(residual.iter().all(|r| r.abs() < 1e-12));
+    }
+}
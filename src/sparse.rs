@@ -0,0 +1,192 @@
+use crate::{Array2d, Matrix};
+use num::Zero;
+use std::ops::AddAssign;
+
+// compressed sparse rows, implicit zeros are read-only
+#[derive(Debug, Clone, PartialEq)]
+pub struct Csr<T> {
+    height: usize,
+    width: usize,
+    offsets: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<T>,
+    zero: T,
+}
+
+impl<T: Zero + AddAssign> Csr<T> {
+    // duplicate entries are summed, None if an entry lies outside the matrix
+    pub fn from_triplets(
+        height: usize,
+        width: usize,
+        mut triplets: Vec<(usize, usize, T)>,
+    ) -> Option<Csr<T>> {
+        if triplets.iter().any(|&(i, j, _)| i >= height || j >= width) {
+            return None;
+        }
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut offsets = vec![0; height + 1];
+        let mut columns: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last = None;
+        for (i, j, value) in triplets {
+            if last == Some((i, j)) {
+                *values.last_mut().expect("A previous entry exists") += value;
+                continue;
+            }
+            last = Some((i, j));
+            offsets[i + 1] += 1;
+            columns.push(j);
+            values.push(value);
+        }
+        for i in 0..height {
+            offsets[i + 1] += offsets[i];
+        }
+        Some(Csr {
+            height,
+            width,
+            offsets,
+            columns,
+            values,
+            zero: T::zero(),
+        })
+    }
+}
+
+impl<T: Zero + AddAssign + Clone> Csr<T> {
+    pub fn from_matrix<M: Matrix<Item = T>>(m: &M) -> Csr<T> {
+        let mut triplets = vec![];
+        for i in 0..m.height() {
+            for j in 0..m.width() {
+                let value = m.at(i, j).expect("Index within matrix dimensions");
+                if !value.is_zero() {
+                    triplets.push((i, j, value.clone()));
+                }
+            }
+        }
+        Csr::from_triplets(m.height(), m.width(), triplets).expect("Entries within dimensions")
+    }
+
+    pub fn to_dense(&self) -> Array2d<T> {
+        Array2d::gen(self.height, self.width, |i, j| {
+            self.at(i, j)
+                .expect("Index within matrix dimensions")
+                .clone()
+        })
+    }
+}
+
+impl<T> Csr<T> {
+    pub fn nonzeros(&self) -> usize {
+        self.values.len()
+    }
+
+    // stored entries in row-major order
+    pub fn entries(&self) -> impl Iterator<Item = (usize, usize, &T)> + '_ {
+        (0..self.height).flat_map(move |i| {
+            (self.offsets[i]..self.offsets[i + 1])
+                .map(move |k| (i, self.columns[k], &self.values[k]))
+        })
+    }
+
+    fn position(&self, row: usize, column: usize) -> Option<usize> {
+        let start = *self.offsets.get(row)?;
+        let end = *self.offsets.get(row + 1)?;
+        self.columns[start..end]
+            .binary_search(&column)
+            .ok()
+            .map(|k| start + k)
+    }
+}
+
+impl Csr<f64> {
+    pub fn mul_vec(&self, x: &[f64]) -> Option<Vec<f64>> {
+        if x.len() != self.width {
+            return None;
+        }
+        Some(
+            (0..self.height)
+                .map(|i| {
+                    (self.offsets[i]..self.offsets[i + 1])
+                        .map(|k| self.values[k] * x[self.columns[k]])
+                        .sum()
+                })
+                .collect(),
+        )
+    }
+}
+
+impl<T> Matrix for Csr<T> {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        if row >= self.height || column >= self.width {
+            None
+        } else {
+            match self.position(row, column) {
+                Some(k) => Some(&self.values[k]),
+                None => Some(&self.zero),
+            }
+        }
+    }
+
+    fn at_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Item> {
+        let k = self.position(row, column)?;
+        Some(&mut self.values[k])
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn swap_rows(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+
+    fn swap_columns(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sparse::Csr;
+    use crate::{Array2d, Matrix};
+
+    #[test]
+    fn triplets_round_trip() {
+        let csr = Csr::from_triplets(
+            3,
+            4,
+            vec![(2, 0, 1.0), (0, 3, 2.0), (0, 1, 3.0), (2, 0, 4.0)],
+        )
+        .unwrap();
+        assert_eq!(csr.nonzeros(), 3);
+        assert_eq!(
+            csr.entries().collect::<Vec<_>>(),
+            vec![(0, 1, &3.0), (0, 3, &2.0), (2, 0, &5.0)]
+        );
+        assert_eq!(csr.at(1, 1), Some(&0.0));
+        assert_eq!(csr.at(3, 0), None);
+
+        let dense = csr.to_dense();
+        assert_eq!(Csr::from_matrix(&dense), csr);
+        assert_eq!(
+            csr.mul_vec(&[1.0, 1.0, 1.0, 1.0]).unwrap(),
+            dense.try_mul_vec(&[1.0, 1.0, 1.0, 1.0]).unwrap()
+        );
+        assert!(Csr::from_triplets(2, 2, vec![(2, 0, 1.0)]).is_none());
+    }
+
+    #[test]
+    fn implicit_zeros_are_read_only() {
+        let mut csr = Csr::from_matrix(&Array2d::try_from(2, 2, vec![1.0, 0.0, 0.0, 2.0]).unwrap());
+        assert!(csr.at_mut(0, 1).is_none());
+        *csr.at_mut(1, 1).unwrap() = 3.0;
+        assert_eq!(csr.at(1, 1), Some(&3.0));
+    }
+}
@@ -0,0 +1,371 @@
+use crate::gauss::{self, Lu, SolveError};
+use crate::io::{json, text};
+use crate::{diagnostics, Array2d, Matrix, Method, Options};
+use either::{Left, Right};
+use rayon::prelude::*;
+use std::io::{BufWriter, Write};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Solver {
+    // `crate::solve` picking the method itself
+    Auto,
+    // `crate::solve` with the method forced
+    Method(Method),
+    Mixed,
+    // [A | b | E] through `gauss::solve`, the workload of the original sweep
+    Inverse,
+}
+
+impl Solver {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Solver::Auto => "auto",
+            Solver::Method(method) => method.name(),
+            Solver::Mixed => "mixed",
+            Solver::Inverse => "inverse",
+        }
+    }
+
+    // x and the number of iterations, if the solver iterates
+    pub fn run(
+        &self,
+        a: &Array2d<f64>,
+        b: &[f64],
+    ) -> Result<(Vec<f64>, Option<usize>), SolveError> {
+        match self {
+            Solver::Auto | Solver::Method(_) => {
+                let options = Options {
+                    method: match self {
+                        Solver::Method(method) => Some(*method),
+                        _ => None,
+                    },
+                    ..Options::default()
+                };
+                let report = crate::solve(a, b, options)?;
+                Ok((report.x, report.iterations))
+            }
+            Solver::Mixed => {
+                let solution = gauss::solve_mixed(a, b)?;
+                Ok((solution.x, Some(solution.refinement.steps)))
+            }
+            Solver::Inverse => {
+                let n = a.height();
+                if b.len() != n {
+                    return Err(SolveError::Dimensions {
+                        height: n,
+                        width: a.width(),
+                        rhs: b.len(),
+                    });
+                }
+                let rhs = Array2d::gen(n, n + 1, |i, j| match j {
+                    0 => b[i],
+                    j if j == i + 1 => 1.0,
+                    _ => 0.0,
+                });
+                let x = gauss::solve(a.clone(), rhs)?;
+                Ok(((0..n).map(|i| *x.at(i, 0).unwrap()).collect(), None))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for Solver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl std::str::FromStr for Solver {
+    type Err = crate::solver::UnknownMethod;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(Solver::Auto),
+            "mixed" => Ok(Solver::Mixed),
+            "inverse" => Ok(Solver::Inverse),
+            _ => s.parse().map(Solver::Method),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Record {
+    pub n: usize,
+    pub solver: Solver,
+    // 0-based
+    pub repetition: usize,
+    pub elapsed: Duration,
+    pub iterations: Option<usize>,
+    // relative, None when the solver failed
+    pub residual: Option<f64>,
+    // of A, shared by every run of the same size
+    pub cond: f64,
+    pub det: f64,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Sweep {
+    // sizes from..=to
+    pub from: usize,
+    pub to: usize,
+    pub step: usize,
+    pub solvers: Vec<Solver>,
+    pub repetitions: usize,
+    // sizes are spread over this many threads, 0 lets rayon pick as in `gauss::stream_batch`
+    pub threads: usize,
+}
+
+impl Default for Sweep {
+    fn default() -> Self {
+        Sweep {
+            from: 10,
+            to: 100,
+            step: 1,
+            solvers: vec![Solver::Inverse],
+            repetitions: 1,
+            threads: 1,
+        }
+    }
+}
+
+impl Sweep {
+    pub fn sizes(&self) -> impl Iterator<Item = usize> {
+        (self.from..=self.to).step_by(self.step.max(1))
+    }
+
+    // One thread runs sizes as the iterator reaches them, sequential so that timings do not
+    // disturb each other. More threads run whole sizes at once and keep them in order.
+    pub fn records<'a, G>(&'a self, generator: G) -> impl Iterator<Item = Record> + 'a
+    where
+        G: Fn(usize) -> (Array2d<f64>, Vec<f64>) + Sync + 'a,
+    {
+        if self.threads == 1 {
+            return Left(self.sizes().flat_map(move |n| self.size(n, &generator)));
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.threads)
+            .build()
+            .expect("Failed to build a thread pool");
+        let sizes: Vec<usize> = self.sizes().collect();
+        let records: Vec<Vec<Record>> = pool.install(|| {
+            sizes
+                .into_par_iter()
+                .map(|n| self.size(n, &generator))
+                .collect()
+        });
+        Right(records.into_iter().flatten())
+    }
+
+    fn size<G>(&self, n: usize, generator: &G) -> Vec<Record>
+    where
+        G: Fn(usize) -> (Array2d<f64>, Vec<f64>),
+    {
+        let (a, b) = generator(n);
+        let cond = crate::cond(&a);
+        let det = match Lu::new(&a) {
+            Ok(lu) => lu.det(),
+            Err(SolveError::Singular) => 0.0,
+            Err(_) => f64::NAN,
+        };
+        let mut records = Vec::with_capacity(self.solvers.len() * self.repetitions);
+        for &solver in &self.solvers {
+            for repetition in 0..self.repetitions {
+                let start = Instant::now();
+                let solved = solver.run(&a, &b);
+                let elapsed = start.elapsed();
+                let (iterations, residual, error) = match solved {
+                    Ok((x, iterations)) => {
+                        (iterations, diagnostics::relative_residual(&a, &x, &b), None)
+                    }
+                    Err(e) => (None, None, Some(e.to_string())),
+                };
+                records.push(Record {
+                    n,
+                    solver,
+                    repetition,
+                    elapsed,
+                    iterations,
+                    residual,
+                    cond,
+                    det,
+                    error,
+                });
+            }
+        }
+        records
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    // one object per line
+    Json,
+}
+
+const COLUMNS: &str = "n,solver,repetition,seconds,iterations,residual,cond,det,error";
+
+impl Record {
+    pub fn csv(&self) -> String {
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        format!(
+            "{},{},{},{:?},{},{},{:?},{:?},{}",
+            self.n,
+            self.solver,
+            self.repetition,
+            self.elapsed.as_secs_f64(),
+            optional(self.iterations.map(|i| i.to_string())),
+            optional(self.residual.map(|r| format!("{:?}", r))),
+            self.cond,
+            self.det,
+            optional(self.error.as_deref().map(text::csv_field))
+        )
+    }
+
+    pub fn json(&self) -> String {
+        let null = |value: Option<String>| value.unwrap_or_else(|| "null".to_string());
+        format!(
+            "{{\"n\":{},\"solver\":\"{}\",\"repetition\":{},\"seconds\":{:?},\"iterations\":{},\"residual\":{},\"cond\":{},\"det\":{},\"error\":{}}}",
+            self.n,
+            self.solver,
+            self.repetition,
+            self.elapsed.as_secs_f64(),
+            null(self.iterations.map(|i| i.to_string())),
+            null(self.residual.map(json::number)),
+            json::number(self.cond),
+            json::number(self.det),
+            null(self.error.as_deref().map(json::string))
+        )
+    }
+}
+
+// flushes after every record so that long sweeps can be followed as they run
+pub fn write<W: Write, I: IntoIterator<Item = Record>>(
+    writer: W,
+    records: I,
+    format: Format,
+) -> std::io::Result<usize> {
+    let mut writer = BufWriter::new(writer);
+    if format == Format::Csv {
+        writeln!(writer, "{}", COLUMNS)?;
+    }
+    let mut count = 0;
+    for record in records {
+        match format {
+            Format::Csv => writeln!(writer, "{}", record.csv())?,
+            Format::Json => writeln!(writer, "{}", record.json())?,
+        }
+        writer.flush()?;
+        count += 1;
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::sweep::{self, Format, Record, Solver, Sweep};
+    use crate::{generators, Array2d, Method};
+
+    fn course(n: usize) -> (Array2d<f64>, Vec<f64>) {
+        (generators::course(n), generators::course_rhs(n))
+    }
+
+    #[test]
+    fn records_every_run() {
+        let sweep = Sweep {
+            from: 10,
+            to: 14,
+            step: 2,
+            solvers: vec![
+                Solver::Inverse,
+                Solver::Auto,
+                Solver::Method(Method::Cholesky),
+            ],
+            repetitions: 2,
+            threads: 1,
+        };
+        let records: Vec<_> = sweep.records(course).collect();
+        assert_eq!(records.len(), 3 * 3 * 2);
+        assert_eq!(
+            records.iter().map(|r| r.n).collect::<Vec<_>>(),
+            [[10; 6], [12; 6], [14; 6]].concat()
+        );
+
+        let first = &records[0];
+        assert_eq!((first.solver, first.repetition), (Solver::Inverse, 0));
+        assert_eq!(first.cond, 495.0);
+        assert!(first.residual.unwrap() < 1e-14);
+        assert!(first.error.is_none());
+        assert!((first.det - crate::gauss::Lu::new(&course(10).0).unwrap().det()).abs() < 1e-9);
+
+        // A is not symmetric, so Cholesky fails on every run without stopping the sweep
+        let cholesky = &records[4];
+        assert_eq!(cholesky.solver, Solver::Method(Method::Cholesky));
+        assert_eq!(cholesky.residual, None);
+        assert_eq!(
+            cholesky.error.as_deref(),
+            Some("The matrix is not symmetric positive definite")
+        );
+
+        // sizes run side by side still come out in order
+        let parallel = Sweep {
+            threads: 3,
+            ..sweep
+        };
+        let runs = |records: &[Record]| {
+            records
+                .iter()
+                .map(|r| (r.n, r.solver, r.repetition, r.residual.is_some()))
+                .collect::<Vec<_>>()
+        };
+        let records_in_parallel: Vec<_> = parallel.records(course).collect();
+        assert_eq!(runs(&records_in_parallel), runs(&records));
+    }
+
+    #[test]
+    fn streams_csv_and_json() {
+        let sweep = Sweep {
+            from: 3,
+            to: 3,
+            solvers: vec!["mixed".parse().unwrap(), "gauss".parse().unwrap()],
+            ..Sweep::default()
+        };
+        let mut csv = vec![];
+        assert_eq!(
+            sweep::write(&mut csv, sweep.records(course), Format::Csv).unwrap(),
+            2
+        );
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "n,solver,repetition,seconds,iterations,residual,cond,det,error"
+        );
+        assert!(lines[1].starts_with("3,mixed,0,"), "{}", lines[1]);
+        assert!(lines[2].starts_with("3,gauss,0,"), "{}", lines[2]);
+        assert!(lines[2].ends_with(",18.0,6.0,"), "{}", lines[2]);
+
+        let mut json = vec![];
+        sweep::write(&mut json, sweep.records(course), Format::Json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        assert_eq!(json.lines().count(), 2);
+        assert!(json.starts_with("{\"n\":3,\"solver\":\"mixed\",\"repetition\":0,\"seconds\":"));
+        let gauss = json.lines().nth(1).unwrap();
+        assert!(
+            gauss.contains("\"iterations\":null,\"residual\":"),
+            "{}",
+            gauss
+        );
+        assert!(gauss.ends_with("\"cond\":18.0,\"det\":6.0,\"error\":null}"));
+
+        assert!("lu".parse::<Solver>().is_err());
+        assert_eq!("Inverse".parse(), Ok(Solver::Inverse));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Array2d, Matrix};
+
+// callbacks of `gauss::calc_l_observed` and `gauss::calc_u_observed`, all of them do nothing by default
+pub trait Observer {
+    // rows `a` and `b` were swapped to find a non-zero pivot
+    fn swap(&mut self, _step: usize, _a: usize, _b: usize) {}
+
+    // after any swap, so `row` is where the pivot ends up
+    fn pivot(&mut self, _step: usize, _row: usize, _column: usize, _value: f64) {}
+
+    // the column of the pivot is eliminated
+    fn eliminated<M: Matrix<Item = f64>>(&mut self, _step: usize, _m: &M) {}
+}
+
+impl Observer for () {}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Step {
+    Swap {
+        step: usize,
+        a: usize,
+        b: usize,
+    },
+    Pivot {
+        step: usize,
+        row: usize,
+        column: usize,
+        value: f64,
+    },
+    Eliminated {
+        step: usize,
+        matrix: Array2d<f64>,
+    },
+}
+
+// records every step, the intermediate matrices only when asked since they cost a copy each
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trace {
+    pub steps: Vec<Step>,
+    matrices: bool,
+}
+
+impl Trace {
+    pub fn new() -> Trace {
+        Trace::default()
+    }
+
+    pub fn with_matrices() -> Trace {
+        Trace {
+            steps: vec![],
+            matrices: true,
+        }
+    }
+
+    pub fn pivots(&self) -> impl Iterator<Item = f64> + '_ {
+        self.steps.iter().filter_map(|step| match step {
+            Step::Pivot { value, .. } => Some(*value),
+            _ => None,
+        })
+    }
+
+    pub fn swaps(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step, Step::Swap { .. }))
+            .count()
+    }
+
+    // of the leading square block, every swap flips the sign
+    pub fn det(&self) -> f64 {
+        let product: f64 = self.pivots().product();
+        if self.swaps().is_multiple_of(2) {
+            product
+        } else {
+            -product
+        }
+    }
+}
+
+impl Observer for Trace {
+    fn swap(&mut self, step: usize, a: usize, b: usize) {
+        self.steps.push(Step::Swap { step, a, b });
+    }
+
+    fn pivot(&mut self, step: usize, row: usize, column: usize, value: f64) {
+        self.steps.push(Step::Pivot {
+            step,
+            row,
+            column,
+            value,
+        });
+    }
+
+    fn eliminated<M: Matrix<Item = f64>>(&mut self, step: usize, m: &M) {
+        if self.matrices {
+            let matrix = Array2d::gen(m.height(), m.width(), |i, j| {
+                *m.at(i, j).expect("Index within matrix dimensions")
+            });
+            self.steps.push(Step::Eliminated { step, matrix });
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::gauss::{self, Lu};
+    use crate::trace::{Step, Trace};
+    use crate::{generators, matrix, Array2d, Meow, Transposed};
+
+    #[test]
+    fn records_swaps_and_pivots() {
+        // zeros on the diagonal force a swap in the first step
+        let mut a = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 1.0; 2.0, 1.0, 3.0];
+        let mut trace = Trace::with_matrices();
+        gauss::calc_l_observed(&mut a, &mut trace).unwrap();
+
+        assert_eq!(
+            trace.steps[0],
+            Step::Swap {
+                step: 0,
+                a: 2,
+                b: 0
+            }
+        );
+        assert_eq!(
+            trace.steps[1],
+            Step::Pivot {
+                step: 0,
+                row: 0,
+                column: 0,
+                value: 2.0
+            }
+        );
+        assert_eq!(trace.swaps(), 1);
+        assert_eq!(trace.pivots().count(), 3);
+        let matrices: Vec<_> = trace
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                Step::Eliminated { matrix, .. } => Some(matrix),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(matrices.len(), 3);
+        assert_eq!(*matrices[2], a);
+        assert!((trace.det() - -3.0).abs() < 1e-12);
+
+        // without matrices only swaps and pivots are kept
+        let mut trace = Trace::new();
+        gauss::calc_l_observed(&mut generators::course(6), &mut trace).unwrap();
+        assert_eq!(trace.steps.len(), trace.pivots().count() + trace.swaps());
+    }
+
+    #[test]
+    fn det_matches_lu() {
+        for n in [1, 2, 5, 10] {
+            let expected = Lu::new(&generators::course(n)).unwrap().det();
+            let mut a = generators::course_augmented(n);
+            let mut l = Trace::new();
+            gauss::calc_l_observed(&mut a, &mut l).unwrap();
+            assert!((l.det() - expected).abs() < 1e-9 * expected.abs());
+
+            // `calc_u` runs on the triangle left by `calc_l`, so its pivots are the same diagonal
+            let mut u = Trace::new();
+            gauss::calc_u_observed(&mut a, &mut u).unwrap();
+            assert_eq!(u.swaps(), 0);
+            let product: f64 = u.pivots().product();
+            assert!((product - l.pivots().product::<f64>()).abs() < 1e-9 * expected.abs());
+        }
+
+        // any `Matrix` is observed, not only `Array2d`
+        let mut m = Meow::from(generators::course(5));
+        m.eat(Array2d::gen(5, 1, |i, _| (i + 1) as f64)).unwrap();
+        let mut trace = Trace::new();
+        gauss::calc_l_observed(&mut m, &mut trace).unwrap();
+        assert!((trace.det() - 120.0).abs() < 1e-9);
+
+        let mut a = generators::pascal(4);
+        let mut trace = Trace::new();
+        gauss::calc_l_observed(&mut Transposed::new(&mut a), &mut trace).unwrap();
+        assert!((trace.det() - 1.0).abs() < 1e-9);
+    }
+}
@@ -1,3 +1,6 @@
+use crate::{Transposed, View, ViewMut};
+use std::ops::Range;
+
 pub trait Matrix {
     type Item;
 
@@ -9,6 +12,92 @@ pub trait Matrix {
 
     fn swap_rows(&mut self, a: usize, b: usize) -> Option<()>;
     fn swap_columns(&mut self, a: usize, b: usize) -> Option<()>;
+
+    fn view(&self, rows: Range<usize>, columns: Range<usize>) -> Option<View<'_, Self>>
+    where
+        Self: Sized,
+    {
+        View::new(self, rows, columns)
+    }
+
+    fn view_mut(&mut self, rows: Range<usize>, columns: Range<usize>) -> Option<ViewMut<'_, Self>>
+    where
+        Self: Sized,
+    {
+        ViewMut::new(self, rows, columns)
+    }
+
+    fn t(&self) -> Transposed<&Self>
+    where
+        Self: Sized,
+    {
+        Transposed::new(self)
+    }
+
+    fn t_mut(&mut self) -> Transposed<&mut Self>
+    where
+        Self: Sized,
+    {
+        Transposed::new(self)
+    }
+}
+
+// Read-only: `at_mut`, `swap_rows` and `swap_columns` always return None, so
+// mutating routines such as `gauss::calc_l` fail on it and leave the origin intact
+impl<M: Matrix<Item = T>, T> Matrix for &M {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        (**self).at(row, column)
+    }
+
+    fn at_mut(&mut self, _row: usize, _column: usize) -> Option<&mut Self::Item> {
+        None
+    }
+
+    fn height(&self) -> usize {
+        (**self).height()
+    }
+
+    fn width(&self) -> usize {
+        (**self).width()
+    }
+
+    fn swap_rows(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+
+    fn swap_columns(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+}
+
+impl<M: Matrix<Item = T>, T> Matrix for &mut M {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        (**self).at(row, column)
+    }
+
+    fn at_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Item> {
+        (**self).at_mut(row, column)
+    }
+
+    fn height(&self) -> usize {
+        (**self).height()
+    }
+
+    fn width(&self) -> usize {
+        (**self).width()
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) -> Option<()> {
+        (**self).swap_rows(a, b)
+    }
+
+    fn swap_columns(&mut self, a: usize, b: usize) -> Option<()> {
+        (**self).swap_columns(a, b)
+    }
 }
 
 pub trait Mapped {
@@ -48,3 +137,27 @@ pub trait Mapped {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{Array2d, Matrix};
+
+    #[test]
+    fn shared_reference_is_read_only() {
+        let a = Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 3.0]).unwrap();
+        let mut r = &a;
+        assert_eq!(r.at(1, 0), Some(&1.0));
+        assert!(r.at_mut(0, 0).is_none());
+        assert!(r.swap_rows(0, 1).is_none());
+        assert!(r.swap_columns(0, 1).is_none());
+
+        let zero_pivot = Array2d::try_from(2, 2, vec![0.0, 1.0, 1.0, 3.0]).unwrap();
+        assert!(crate::gauss::calc_l(&mut a.t()).is_none());
+        assert!(crate::gauss::calc_l(&mut zero_pivot.t()).is_none());
+        assert_eq!(
+            a,
+            Array2d::try_from(2, 2, vec![2.0, 1.0, 1.0, 3.0]).unwrap()
+        );
+        assert_eq!(*zero_pivot.at(0, 0).unwrap(), 0.0);
+    }
+}
@@ -1,9 +1,24 @@
 use crate::Matrix;
 
+#[derive(Debug, Clone)]
 pub struct Transposed<T> {
     origin: T,
 }
 
+impl<T> Transposed<T> {
+    pub fn new(origin: T) -> Transposed<T> {
+        Transposed { origin }
+    }
+
+    pub fn origin(&self) -> &T {
+        &self.origin
+    }
+
+    pub fn into_inner(self) -> T {
+        self.origin
+    }
+}
+
 impl<M: Matrix<Item = T>, T> Matrix for Transposed<M> {
     type Item = T;
 
@@ -31,3 +46,90 @@ impl<M: Matrix<Item = T>, T> Matrix for Transposed<M> {
         self.origin.swap_rows(a, b)
     }
 }
+
+impl<M: Matrix<Item = T>, T: PartialEq> PartialEq for Transposed<M> {
+    fn eq(&self, other: &Self) -> bool {
+        if self.height() != other.height() {
+            return false;
+        }
+        if self.width() != other.width() {
+            return false;
+        }
+
+        for i in 0..self.height() {
+            for j in 0..self.width() {
+                if self.at(i, j) != other.at(i, j) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl<M: Matrix<Item = f64>> std::fmt::Display for Transposed<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use crate::Iteratable;
+        for row in self.rows() {
+            write!(f, "|")?;
+            for cell in row {
+                write!(f, " {:.2}", cell)?;
+            }
+            writeln!(f, " |")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{jacobi, Array2d, Iteratable, Matrix, Transposed};
+
+    #[test]
+    fn transposed_access_ok() {
+        let m = Array2d::gen(2, 3, |i, j| (i, j));
+        let t = m.t();
+        assert_eq!(t.height(), 3);
+        assert_eq!(t.width(), 2);
+        for (row_id, row) in t.rows().enumerate() {
+            for (column_id, &(i, j)) in row.enumerate() {
+                assert_eq!(i, column_id);
+                assert_eq!(j, row_id);
+            }
+        }
+    }
+
+    #[test]
+    fn double_transpose_is_identity() {
+        let m = Array2d::gen(3, 4, |i, j| i * 4 + j);
+        let tt = Transposed::new(Transposed::new(m.clone()));
+        let expected = Transposed::new(Transposed::new(Array2d::gen(3, 4, |i, j| i * 4 + j)));
+        assert_eq!(tt, expected);
+        assert_eq!(tt.into_inner().into_inner(), m);
+    }
+
+    #[test]
+    fn t_mut_writes_through() {
+        let mut m = Array2d::gen(2, 2, |_, _| 0);
+        *m.t_mut().at_mut(0, 1).unwrap() = 1;
+        m.t_mut().swap_rows(0, 1).unwrap();
+        let expected = Array2d::try_from(2, 2, vec![0, 0, 0, 1]).unwrap();
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn l_on_transposed() {
+        let mut a = Array2d::try_from(2, 2, vec![2.0, 4.0, 1.0, 3.0]).unwrap();
+        crate::gauss::calc_l(&mut a.t_mut());
+        let expected = Array2d::try_from(2, 2, vec![2.0, 0.0, 1.0, 1.0]).unwrap();
+        assert_eq!(a, expected);
+    }
+
+    #[test]
+    fn jacobi_on_transposed() {
+        let a = Array2d::try_from(2, 2, vec![2.0, 3.0, 1.0, 4.0]).unwrap();
+        assert!(!jacobi::converges_for(&a));
+        assert!(jacobi::converges_for(&a.t()));
+    }
+}
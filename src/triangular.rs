@@ -0,0 +1,410 @@
+use crate::{Array2d, Matrix};
+use num::{One, Zero};
+
+pub struct UpperTriangular<M: Matrix> {
+    origin: M,
+    unit: bool,
+    zero: M::Item,
+    one: M::Item,
+}
+
+pub struct LowerTriangular<M: Matrix> {
+    origin: M,
+    unit: bool,
+    zero: M::Item,
+    one: M::Item,
+}
+
+pub struct Diagonal<M: Matrix> {
+    origin: M,
+    zero: M::Item,
+}
+
+impl<M: Matrix<Item = T>, T: Zero + One> UpperTriangular<M> {
+    pub fn new(origin: M) -> UpperTriangular<M> {
+        UpperTriangular {
+            origin,
+            unit: false,
+            zero: T::zero(),
+            one: T::one(),
+        }
+    }
+
+    pub fn unit(origin: M) -> UpperTriangular<M> {
+        UpperTriangular {
+            unit: true,
+            ..UpperTriangular::new(origin)
+        }
+    }
+}
+
+impl<M: Matrix<Item = T>, T: Zero + One> LowerTriangular<M> {
+    pub fn new(origin: M) -> LowerTriangular<M> {
+        LowerTriangular {
+            origin,
+            unit: false,
+            zero: T::zero(),
+            one: T::one(),
+        }
+    }
+
+    pub fn unit(origin: M) -> LowerTriangular<M> {
+        LowerTriangular {
+            unit: true,
+            ..LowerTriangular::new(origin)
+        }
+    }
+}
+
+impl<M: Matrix<Item = T>, T: Zero> Diagonal<M> {
+    pub fn new(origin: M) -> Diagonal<M> {
+        Diagonal {
+            origin,
+            zero: T::zero(),
+        }
+    }
+}
+
+impl<M: Matrix> UpperTriangular<M> {
+    pub fn into_inner(self) -> M {
+        self.origin
+    }
+}
+
+impl<M: Matrix> LowerTriangular<M> {
+    pub fn into_inner(self) -> M {
+        self.origin
+    }
+}
+
+impl<M: Matrix> Diagonal<M> {
+    pub fn into_inner(self) -> M {
+        self.origin
+    }
+}
+
+fn inside<M: Matrix>(m: &M, row: usize, column: usize) -> bool {
+    row < m.height() && column < m.width()
+}
+
+impl<M: Matrix<Item = T>, T> Matrix for UpperTriangular<M> {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        if !inside(&self.origin, row, column) {
+            None
+        } else if row > column {
+            Some(&self.zero)
+        } else if row == column && self.unit {
+            Some(&self.one)
+        } else {
+            self.origin.at(row, column)
+        }
+    }
+
+    fn at_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Item> {
+        if row > column || (row == column && self.unit) {
+            None
+        } else {
+            self.origin.at_mut(row, column)
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.origin.height()
+    }
+
+    fn width(&self) -> usize {
+        self.origin.width()
+    }
+
+    fn swap_rows(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+
+    fn swap_columns(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+}
+
+impl<M: Matrix<Item = T>, T> Matrix for LowerTriangular<M> {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        if !inside(&self.origin, row, column) {
+            None
+        } else if row < column {
+            Some(&self.zero)
+        } else if row == column && self.unit {
+            Some(&self.one)
+        } else {
+            self.origin.at(row, column)
+        }
+    }
+
+    fn at_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Item> {
+        if row < column || (row == column && self.unit) {
+            None
+        } else {
+            self.origin.at_mut(row, column)
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.origin.height()
+    }
+
+    fn width(&self) -> usize {
+        self.origin.width()
+    }
+
+    fn swap_rows(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+
+    fn swap_columns(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+}
+
+impl<M: Matrix<Item = T>, T> Matrix for Diagonal<M> {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        if !inside(&self.origin, row, column) {
+            None
+        } else if row != column {
+            Some(&self.zero)
+        } else {
+            self.origin.at(row, column)
+        }
+    }
+
+    fn at_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Item> {
+        if row != column {
+            None
+        } else {
+            self.origin.at_mut(row, column)
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.origin.height()
+    }
+
+    fn width(&self) -> usize {
+        self.origin.width()
+    }
+
+    fn swap_rows(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+
+    fn swap_columns(&mut self, _a: usize, _b: usize) -> Option<()> {
+        None
+    }
+}
+
+fn pivot<M: Matrix<Item = f64>>(m: &M, i: usize) -> Option<f64> {
+    let diag = *m.at(i, i)?;
+    if diag == 0.0 {
+        None
+    } else {
+        Some(diag)
+    }
+}
+
+fn columns<S, B>(solve: S, b: &mut B) -> Option<()>
+where
+    S: Fn(&mut [f64]) -> Option<()>,
+    B: Matrix<Item = f64>,
+{
+    let mut x = vec![0.0; b.height()];
+    for column in 0..b.width() {
+        for (i, value) in x.iter_mut().enumerate() {
+            *value = *b.at(i, column)?;
+        }
+        solve(&mut x)?;
+        for (i, &value) in x.iter().enumerate() {
+            *b.at_mut(i, column)? = value;
+        }
+    }
+    Some(())
+}
+
+impl<M: Matrix<Item = f64>> UpperTriangular<M> {
+    pub fn solve(&self, b: &mut [f64]) -> Option<()> {
+        let n = self.height();
+        if n != self.width() || n != b.len() {
+            return None;
+        }
+        for i in (0..n).rev() {
+            let mut value = b[i];
+            for (j, x) in b.iter().enumerate().skip(i + 1) {
+                value -= self.at(i, j)? * x;
+            }
+            b[i] = value / pivot(self, i)?;
+        }
+        Some(())
+    }
+
+    pub fn solve_columns<B: Matrix<Item = f64>>(&self, b: &mut B) -> Option<()> {
+        columns(|x| self.solve(x), b)
+    }
+
+    pub fn solved(&self, b: &Array2d<f64>) -> Option<Array2d<f64>> {
+        let mut x = b.clone();
+        self.solve_columns(&mut x)?;
+        Some(x)
+    }
+}
+
+impl<M: Matrix<Item = f64>> LowerTriangular<M> {
+    pub fn solve(&self, b: &mut [f64]) -> Option<()> {
+        let n = self.height();
+        if n != self.width() || n != b.len() {
+            return None;
+        }
+        for i in 0..n {
+            let mut value = b[i];
+            for (j, x) in b.iter().enumerate().take(i) {
+                value -= self.at(i, j)? * x;
+            }
+            b[i] = value / pivot(self, i)?;
+        }
+        Some(())
+    }
+
+    pub fn solve_columns<B: Matrix<Item = f64>>(&self, b: &mut B) -> Option<()> {
+        columns(|x| self.solve(x), b)
+    }
+
+    pub fn solved(&self, b: &Array2d<f64>) -> Option<Array2d<f64>> {
+        let mut x = b.clone();
+        self.solve_columns(&mut x)?;
+        Some(x)
+    }
+}
+
+impl<M: Matrix<Item = f64>> Diagonal<M> {
+    pub fn solve(&self, b: &mut [f64]) -> Option<()> {
+        let n = self.height();
+        if n != self.width() || n != b.len() {
+            return None;
+        }
+        for (i, value) in b.iter_mut().enumerate() {
+            *value /= pivot(self, i)?;
+        }
+        Some(())
+    }
+
+    pub fn solve_columns<B: Matrix<Item = f64>>(&self, b: &mut B) -> Option<()> {
+        columns(|x| self.solve(x), b)
+    }
+
+    pub fn solved(&self, b: &Array2d<f64>) -> Option<Array2d<f64>> {
+        let mut x = b.clone();
+        self.solve_columns(&mut x)?;
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{gauss, Array2d, Diagonal, LowerTriangular, Matrix, Meow, UpperTriangular};
+
+    fn full() -> Array2d<f64> {
+        Array2d::try_from(
+            3,
+            3,
+            vec![
+                2.0, 1.0, 3.0, //
+                4.0, 5.0, 6.0, //
+                7.0, 8.0, 9.0, //
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn implicit_zeros_are_read_only() {
+        let mut u = UpperTriangular::new(full());
+        assert_eq!(u.at(2, 0), Some(&0.0));
+        assert_eq!(u.at(0, 2), Some(&3.0));
+        assert!(u.at_mut(2, 0).is_none());
+        assert!(u.at_mut(0, 2).is_some());
+        assert!(u.at(3, 0).is_none());
+
+        let mut l = LowerTriangular::unit(full());
+        assert_eq!(l.at(1, 1), Some(&1.0));
+        assert_eq!(l.at(0, 1), Some(&0.0));
+        assert!(l.at_mut(1, 1).is_none());
+        assert!(l.swap_rows(0, 1).is_none());
+
+        let d = Diagonal::new(full());
+        assert_eq!(d.at(1, 1), Some(&5.0));
+        assert_eq!(d.at(1, 2), Some(&0.0));
+    }
+
+    #[test]
+    fn upper_solve_ok() {
+        let u = UpperTriangular::new(full());
+        let mut b = vec![6.0, 11.0, 9.0];
+        u.solve(&mut b).unwrap();
+        assert_eq!(b, vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn lower_solve_ok() {
+        let l = LowerTriangular::new(full());
+        let mut b = vec![2.0, 9.0, 24.0];
+        l.solve(&mut b).unwrap();
+        assert_eq!(b, vec![1.0, 1.0, 1.0]);
+
+        let l = LowerTriangular::unit(full());
+        let mut b = vec![1.0, 5.0, 16.0];
+        l.solve(&mut b).unwrap();
+        assert_eq!(b, vec![1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn diagonal_solve_columns_ok() {
+        let d = Diagonal::new(full());
+        let b = Array2d::try_from(3, 2, vec![2.0, 4.0, 5.0, 10.0, 9.0, 18.0]).unwrap();
+        let x = d.solved(&b).unwrap();
+        let expected = Array2d::try_from(3, 2, vec![1.0, 2.0, 1.0, 2.0, 1.0, 2.0]).unwrap();
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn singular_is_reported() {
+        let a = Array2d::try_from(2, 2, vec![1.0, 1.0, 0.0, 0.0]).unwrap();
+        assert!(UpperTriangular::new(a).solve(&mut [1.0, 1.0]).is_none());
+    }
+
+    #[test]
+    fn solve_after_l_matches_u() {
+        let a = full();
+        let b = Array2d::try_from(3, 1, vec![1.0, 2.0, 3.0]).unwrap();
+
+        let mut m = Meow::from(a.clone());
+        m.eat(b.clone()).unwrap();
+        gauss::calc_l(&mut m);
+        let mut x = m.calculate(1).unwrap();
+        UpperTriangular::new(m.part(0).unwrap())
+            .solve_columns(&mut x)
+            .unwrap();
+
+        let mut m = Meow::from(a);
+        m.eat(b).unwrap();
+        gauss::calc_l(&mut m);
+        gauss::calc_u(&mut m);
+        gauss::normalize(&mut m);
+        let expected = m.calculate(1).unwrap();
+
+        for i in 0..3 {
+            let (x, e) = (x.at(i, 0).unwrap(), expected.at(i, 0).unwrap());
+            assert!((x - e).abs() < 1e-12, "{} != {}", x, e);
+        }
+    }
+}
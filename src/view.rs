@@ -0,0 +1,312 @@
+use crate::Matrix;
+use either::{Either, Left, Right};
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+struct Selection {
+    rows: Either<Range<usize>, Vec<usize>>,
+    columns: Either<Range<usize>, Vec<usize>>,
+}
+
+fn fits(axis: &Either<Range<usize>, Vec<usize>>, bound: usize) -> bool {
+    match axis.as_ref() {
+        Left(range) => range.start <= range.end && range.end <= bound,
+        Right(v) => v.iter().all(|&i| i < bound),
+    }
+}
+
+fn len(axis: &Either<Range<usize>, Vec<usize>>) -> usize {
+    match axis.as_ref() {
+        Left(range) => range.len(),
+        Right(v) => v.len(),
+    }
+}
+
+fn map(axis: &Either<Range<usize>, Vec<usize>>, index: usize) -> Option<usize> {
+    match axis.as_ref() {
+        Left(range) => {
+            if index < range.len() {
+                Some(range.start + index)
+            } else {
+                None
+            }
+        }
+        Right(v) => v.get(index).cloned(),
+    }
+}
+
+fn swap(axis: &mut Either<Range<usize>, Vec<usize>>, a: usize, b: usize) -> Option<()> {
+    if a >= len(axis) || b >= len(axis) {
+        return None;
+    }
+    match axis {
+        Left(range) => {
+            let mut v: Vec<usize> = range.clone().collect();
+            v.swap(a, b);
+            *axis = Right(v);
+        }
+        Right(v) => v.swap(a, b),
+    }
+    Some(())
+}
+
+impl Selection {
+    fn new<M: Matrix>(
+        origin: &M,
+        rows: Either<Range<usize>, Vec<usize>>,
+        columns: Either<Range<usize>, Vec<usize>>,
+    ) -> Option<Selection> {
+        if fits(&rows, origin.height()) && fits(&columns, origin.width()) {
+            Some(Selection { rows, columns })
+        } else {
+            None
+        }
+    }
+
+    fn cell(&self, row: usize, column: usize) -> Option<(usize, usize)> {
+        Some((map(&self.rows, row)?, map(&self.columns, column)?))
+    }
+}
+
+pub struct View<'a, M> {
+    origin: &'a M,
+    selection: Selection,
+}
+
+impl<'a, M: Matrix> View<'a, M> {
+    pub fn new(origin: &'a M, rows: Range<usize>, columns: Range<usize>) -> Option<View<'a, M>> {
+        let selection = Selection::new(origin, Left(rows), Left(columns))?;
+        Some(View { origin, selection })
+    }
+
+    pub fn select(origin: &'a M, rows: Vec<usize>, columns: Vec<usize>) -> Option<View<'a, M>> {
+        let selection = Selection::new(origin, Right(rows), Right(columns))?;
+        Some(View { origin, selection })
+    }
+
+    pub fn origin(&self) -> &'a M {
+        self.origin
+    }
+}
+
+impl<'a, M> Clone for View<'a, M> {
+    fn clone(&self) -> Self {
+        View {
+            origin: self.origin,
+            selection: self.selection.clone(),
+        }
+    }
+}
+
+impl<'a, M: Matrix<Item = T>, T> Matrix for View<'a, M> {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        let (row, column) = self.selection.cell(row, column)?;
+        self.origin.at(row, column)
+    }
+
+    fn at_mut(&mut self, _row: usize, _column: usize) -> Option<&mut Self::Item> {
+        None
+    }
+
+    fn height(&self) -> usize {
+        len(&self.selection.rows)
+    }
+
+    fn width(&self) -> usize {
+        len(&self.selection.columns)
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) -> Option<()> {
+        swap(&mut self.selection.rows, a, b)
+    }
+
+    fn swap_columns(&mut self, a: usize, b: usize) -> Option<()> {
+        swap(&mut self.selection.columns, a, b)
+    }
+}
+
+pub struct ViewMut<'a, M> {
+    origin: &'a mut M,
+    selection: Selection,
+}
+
+impl<'a, M: Matrix> ViewMut<'a, M> {
+    pub fn new(
+        origin: &'a mut M,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> Option<ViewMut<'a, M>> {
+        let selection = Selection::new(origin, Left(rows), Left(columns))?;
+        Some(ViewMut { origin, selection })
+    }
+
+    pub fn select(
+        origin: &'a mut M,
+        rows: Vec<usize>,
+        columns: Vec<usize>,
+    ) -> Option<ViewMut<'a, M>> {
+        let selection = Selection::new(origin, Right(rows), Right(columns))?;
+        Some(ViewMut { origin, selection })
+    }
+
+    pub fn view(&self) -> View<'_, M> {
+        View {
+            origin: self.origin,
+            selection: self.selection.clone(),
+        }
+    }
+}
+
+impl<'a, M: Matrix<Item = T>, T> Matrix for ViewMut<'a, M> {
+    type Item = T;
+
+    fn at(&self, row: usize, column: usize) -> Option<&Self::Item> {
+        let (row, column) = self.selection.cell(row, column)?;
+        self.origin.at(row, column)
+    }
+
+    fn at_mut(&mut self, row: usize, column: usize) -> Option<&mut Self::Item> {
+        let (row, column) = self.selection.cell(row, column)?;
+        self.origin.at_mut(row, column)
+    }
+
+    fn height(&self) -> usize {
+        len(&self.selection.rows)
+    }
+
+    fn width(&self) -> usize {
+        len(&self.selection.columns)
+    }
+
+    // whole rows of the origin move, so pivoting on a trailing block permutes the full matrix
+    fn swap_rows(&mut self, a: usize, b: usize) -> Option<()> {
+        let rows = &self.selection.rows;
+        let (a, b) = (map(rows, a)?, map(rows, b)?);
+        self.origin.swap_rows(a, b)
+    }
+
+    fn swap_columns(&mut self, a: usize, b: usize) -> Option<()> {
+        let columns = &self.selection.columns;
+        let (a, b) = (map(columns, a)?, map(columns, b)?);
+        self.origin.swap_columns(a, b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Array2d, Iteratable, Matrix, View, ViewMut};
+
+    #[test]
+    fn range_view_ok() {
+        let m = Array2d::gen(5, 5, |i, j| (i, j));
+        let v = View::new(&m, 1..4, 2..5).unwrap();
+        assert_eq!(v.height(), 3);
+        assert_eq!(v.width(), 3);
+        for (row_id, row) in v.rows().enumerate() {
+            for (column_id, &(i, j)) in row.enumerate() {
+                assert_eq!(i, row_id + 1);
+                assert_eq!(j, column_id + 2);
+            }
+        }
+        assert!(v.at(3, 0).is_none());
+    }
+
+    #[test]
+    fn selected_view_ok() {
+        let m = Array2d::gen(5, 5, |i, j| (i, j));
+        let v = View::select(&m, vec![4, 0], vec![3, 1, 1]).unwrap();
+        assert_eq!(v.at(0, 0), Some(&(4, 3)));
+        assert_eq!(v.at(1, 2), Some(&(0, 1)));
+        assert!(View::select(&m, vec![5], vec![0]).is_none());
+        assert!(View::new(&m, 0..6, 0..1).is_none());
+    }
+
+    #[test]
+    fn view_swap_is_local() {
+        let m = Array2d::gen(4, 4, |i, j| (i, j));
+        let mut v = m.view(1..3, 0..4).unwrap();
+        v.swap_rows(0, 1).unwrap();
+        assert_eq!(v.at(0, 0), Some(&(2, 0)));
+        assert_eq!(m.at(1, 0), Some(&(1, 0)));
+        assert!(v.swap_rows(0, 2).is_none());
+    }
+
+    #[test]
+    fn view_mut_writes_through() {
+        let mut m = Array2d::gen(3, 3, |_, _| 0);
+        {
+            let mut v = ViewMut::new(&mut m, 1..3, 1..3).unwrap();
+            for i in 0..v.height() {
+                for j in 0..v.width() {
+                    *v.at_mut(i, j).unwrap() = 1;
+                }
+            }
+        }
+        let expected = Array2d::try_from(3, 3, vec![0, 0, 0, 0, 1, 1, 0, 1, 1]).unwrap();
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn l_on_trailing_submatrix() {
+        let mut a = Array2d::try_from(
+            3,
+            3,
+            vec![
+                9.0, 9.0, 9.0, //
+                9.0, 2.0, 1.0, //
+                9.0, 4.0, 3.0, //
+            ],
+        )
+        .unwrap();
+        crate::gauss::calc_l(&mut a.view_mut(1..3, 1..3).unwrap());
+
+        let expected = Array2d::try_from(
+            3,
+            3,
+            vec![
+                9.0, 9.0, 9.0, //
+                9.0, 2.0, 1.0, //
+                9.0, 0.0, 1.0, //
+            ],
+        )
+        .unwrap();
+        assert_eq!(a, expected);
+    }
+    #[test]
+    fn view_mut_swaps_the_origin() {
+        let mut a = Array2d::try_from(
+            3,
+            3,
+            vec![
+                9.0, 9.0, 9.0, //
+                1.0, 0.0, 1.0, //
+                2.0, 3.0, 4.0, //
+            ],
+        )
+        .unwrap();
+        // the zero pivot of the trailing block swaps rows 1 and 2 of `a`
+        crate::gauss::calc_l(&mut a.view_mut(1..3, 1..3).unwrap()).unwrap();
+
+        let expected = Array2d::try_from(
+            3,
+            3,
+            vec![
+                9.0, 9.0, 9.0, //
+                2.0, 3.0, 4.0, //
+                1.0, 0.0, 1.0, //
+            ],
+        )
+        .unwrap();
+        assert_eq!(a, expected);
+
+        let mut m = Array2d::gen(3, 3, |i, j| (i, j));
+        let mut v = ViewMut::select(&mut m, vec![2, 0], vec![1, 2]).unwrap();
+        v.swap_columns(0, 1).unwrap();
+        assert_eq!(v.at(0, 0), Some(&(2, 2)));
+        assert!(v.swap_rows(0, 2).is_none());
+        assert_eq!(m.at(0, 1), Some(&(0, 2)));
+        assert_eq!(m.at(0, 0), Some(&(0, 0)));
+    }
+}